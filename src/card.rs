@@ -8,13 +8,17 @@
 
 use crate::render::ansi::ANSIColor;
 use crate::render::engine::{BoxDrawingProfile, RenderResult, RenderableElement, TextFrameBuffer};
-use enum_iterator::Sequence;
+use enum_iterator::{all, Sequence};
+use serde::{Deserialize, Serialize};
 use std::cmp::PartialEq;
 use std::fmt::Display;
 use std::hash::Hash;
+use std::str::FromStr;
 
 /// A struct representing a card, with `value` and `suit` fields.
-#[derive(Debug, Copy, Clone, PartialEq, Hash, Sequence)]
+///
+/// A joker is a card with the value `Value::Joker`, and its suit only says what colour it is: hearts for the red joker and spades for the black one (see `Card::joker`).
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Sequence, Serialize, Deserialize)]
 pub struct Card {
     value: Value,
     suit: Suit,
@@ -35,11 +39,53 @@ impl Card {
     pub fn value(&self) -> Value {
         self.value
     }
+
+    /// Makes a red joker if `red`, or a black one.
+    pub fn joker(red: bool) -> Self {
+        Self::new(Value::Joker, if red { Suit::Hearts } else { Suit::Spades })
+    }
+
+    /// Returns whether the card is a joker.
+    pub fn is_joker(&self) -> bool {
+        self.value == Value::Joker
+    }
+
+    /// Returns whether a deck could have this card. Every card can be, except jokers with a suit other than hearts or spades, which don't exist.
+    pub fn is_valid(&self) -> bool {
+        !self.is_joker() || matches!(self.suit, Suit::Hearts | Suit::Spades)
+    }
+
+    /// Returns every card a deck could have (see `Card::is_valid`): the 52 standard cards, then the red and black jokers.
+    pub fn every() -> impl Iterator<Item = Card> {
+        all::<Card>().filter(Card::is_valid)
+    }
+
+    /// Returns the name of the card's suit, or the colour of a joker, which has no suit. Eg. "Spades" or "Red".
+    pub fn suit_name(&self) -> String {
+        match (self.is_joker(), self.suit.is_red()) {
+            (false, _) => self.suit.full_name(),
+            (true, true) => String::from("Red"),
+            (true, false) => String::from("Black"),
+        }
+    }
+
+    /// Returns the whole name of the card. Eg. "Ace of Spades" or "Red Joker".
+    pub fn full_name(&self) -> String {
+        if self.is_joker() {
+            format!("{} {}", self.suit_name(), self.value.full_name())
+        } else {
+            format!("{} of {}", self.value.full_name(), self.suit.full_name())
+        }
+    }
 }
 
 impl Display for Card {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}{}", self.value, self.suit)
+        if self.is_joker() {
+            write!(f, "{}", self.full_name())
+        } else {
+            write!(f, "{}{}", self.value, self.suit)
+        }
     }
 }
 
@@ -58,6 +104,18 @@ impl RenderableElement for RenderableCard {
         fb.fill_box(' ', x + 1, y + 1, Self::W - 2, Self::H - 2)?;
 
         match self {
+            Self::Front(card) if card.is_joker() => {
+                // Jokers have no value or suit to show, just what colour they are.
+                let color = card.suit().color();
+
+                fb.text("JK", x + 1, y + 1)?;
+                fb.text("JK", x + Self::W - 3, y + Self::H - 2)?;
+                fb.text("\u{2605}", x + Self::W / 2, y + Self::H / 2)?;
+
+                fb.style_clear_color_box(x, y, Self::W, Self::H)?;
+                fb.style_fg_box(color, x + 1, y + 1, Self::W - 2, Self::H - 2)?;
+            }
+
             Self::Front(card) => {
                 let value = card.value();
                 let value_str = value.name();
@@ -93,7 +151,7 @@ impl RenderableElement for RenderableCard {
 }
 
 /// An enum of all the possible values a card can have.
-#[derive(Debug, Copy, Clone, PartialEq, Hash, Sequence)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Sequence, Serialize, Deserialize)]
 pub enum Value {
     Ace,
     Two,
@@ -108,12 +166,14 @@ pub enum Value {
     Jack,
     Queen,
     King,
+    /// Only in decks with jokers (see `deck::DeckKind`).
+    Joker,
 }
 
 impl Value {
     /// Returns the "name" of the value, or what would be displayed on a standard set of cards.
     fn name(&self) -> &str {
-        &match self {
+        match self {
             Self::Ace => "A",
             Self::Two => "2",
             Self::Three => "3",
@@ -127,11 +187,12 @@ impl Value {
             Self::Jack => "J",
             Self::Queen => "Q",
             Self::King => "K",
+            Self::Joker => "JK",
         }
     }
 
-    /// Returns the effective count value for the card. Eg. Ace: 1, 2: 2, ..., J: 11, Q: 12, K: 13.
-    fn count(&self) -> u8 {
+    /// Returns the effective count value for the card. Eg. Ace: 1, 2: 2, ..., J: 11, Q: 12, K: 13. Jokers count as 0.
+    pub fn count(&self) -> u8 {
         match self {
             Self::Ace => 1,
             Self::Two => 2,
@@ -146,9 +207,15 @@ impl Value {
             Self::Jack => 11,
             Self::Queen => 12,
             Self::King => 13,
+            Self::Joker => 0,
         }
     }
 
+    /// Returns whether the value is a face card: a jack, queen or king.
+    pub fn is_face(&self) -> bool {
+        matches!(self, Self::Jack | Self::Queen | Self::King)
+    }
+
     pub fn full_name(&self) -> String {
         String::from(match self {
             Self::Ace => "Ace",
@@ -164,26 +231,33 @@ impl Value {
             Self::Jack => "Jack",
             Self::Queen => "Queen",
             Self::King => "King",
+            Self::Joker => "Joker",
         })
     }
+}
+
+impl FromStr for Value {
+    type Err = ();
 
-    pub fn from_str(str: &str) -> Option<Self> {
-      match str.to_uppercase().as_str() {
-            "A" | "ACE" => Some(Self::Ace),
-            "2" | "TWO" => Some(Self::Two),
-            "3" | "THREE" => Some(Self::Three),
-            "4" | "FOUR" => Some(Self::Four),
-            "5" | "FIVE" => Some(Self::Five),
-            "6" | "SIX" => Some(Self::Six),
-            "7" | "SEVEN" => Some(Self::Seven),
-            "8" | "EIGHT" => Some(Self::Eight),
-            "9" | "NINE" => Some(Self::Nine),
-            "10" | "TEN" => Some(Self::Ten),
-            "J" | "JACK" => Some(Self::Jack),
-            "Q" | "QUEEN" => Some(Self::Queen),
-            "K" | "KING" => Some(Self::King),
-        _ => None,
-      }
+    /// Parses a value from either its name or its full name, ignoring case. Eg. "q" or "Queen".
+    fn from_str(str: &str) -> Result<Self, Self::Err> {
+        match str.to_uppercase().as_str() {
+            "A" | "ACE" => Ok(Self::Ace),
+            "2" | "TWO" => Ok(Self::Two),
+            "3" | "THREE" => Ok(Self::Three),
+            "4" | "FOUR" => Ok(Self::Four),
+            "5" | "FIVE" => Ok(Self::Five),
+            "6" | "SIX" => Ok(Self::Six),
+            "7" | "SEVEN" => Ok(Self::Seven),
+            "8" | "EIGHT" => Ok(Self::Eight),
+            "9" | "NINE" => Ok(Self::Nine),
+            "10" | "TEN" => Ok(Self::Ten),
+            "J" | "JACK" => Ok(Self::Jack),
+            "Q" | "QUEEN" => Ok(Self::Queen),
+            "K" | "KING" => Ok(Self::King),
+            "JK" | "JOKER" => Ok(Self::Joker),
+            _ => Err(()),
+        }
     }
 }
 
@@ -194,7 +268,7 @@ impl Display for Value {
 }
 
 /// Enum representing all the possible suits for a card.
-#[derive(Debug, Copy, Clone, PartialEq, Hash, Sequence)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Sequence, Serialize, Deserialize)]
 pub enum Suit {
     Clubs,
     Diamonds,
@@ -205,7 +279,7 @@ pub enum Suit {
 impl Suit {
     /// Returns the name of the suit (what would be displayed).
     fn name(&self) -> &str {
-        &match self {
+        match self {
             Self::Clubs => "\u{2663}",
             Self::Diamonds => "\u{2666}",
             Self::Hearts => "\u{2665}",
@@ -242,15 +316,20 @@ impl Suit {
             Self::Spades => "Spades",
         })
     }
+}
+
+impl FromStr for Suit {
+    type Err = ();
 
-  pub fn from_str(str: &str) -> Option<Self> {
-      match str.to_uppercase().as_str() {
-            "CLUBS" => Some(Self::Clubs),
-            "HEARTS" => Some(Self::Hearts),
-            "DIAMONDS" => Some(Self::Diamonds),
-            "SPADES" => Some(Self::Spades),
-        _ => None,
-      }
+    /// Parses a suit from its full name, ignoring case. Eg. "spades".
+    fn from_str(str: &str) -> Result<Self, Self::Err> {
+        match str.to_uppercase().as_str() {
+            "CLUBS" => Ok(Self::Clubs),
+            "HEARTS" => Ok(Self::Hearts),
+            "DIAMONDS" => Ok(Self::Diamonds),
+            "SPADES" => Ok(Self::Spades),
+            _ => Err(()),
+        }
     }
 }
 
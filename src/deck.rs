@@ -5,22 +5,36 @@
  * able to shuffle itself, deal cards, and more. 
  */
 
-use crate::card::Card;
+use crate::card::{Card, Value};
 use crate::player::Player;
 use enum_iterator::all;
 use rand::seq::SliceRandom;
-use rand::thread_rng;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
 use std::collections::VecDeque;
 use std::iter::IntoIterator;
 use std::ops::{Index, IndexMut};
+use std::str::FromStr;
 
 /// A struct representing a deck.
 /// Implemented as just a `VecDeque<mao::card::Card>`.
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Deck {
     cards: VecDeque<Card>, // upside-down stack (last element is last card in stack)
 }
 
+/// Which cards a deck is made of, before any jokers are added.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum DeckKind {
+    /// All 52 cards, ace to king in every suit.
+    #[default]
+    Standard,
+    /// The 32 cards from 7 up to ace in every suit, as used for piquet.
+    Piquet,
+    /// The 24 cards from 9 up to ace in every suit, as used for euchre.
+    Euchre,
+}
+
 impl Deck {
     /// Creates a new `Deck` from `cards`.
     pub fn new(cards: VecDeque<Card>) -> Self {
@@ -29,8 +43,16 @@ impl Deck {
 
     /// Returns a default, unshuffled `Deck` of 52 `Card`s.
     pub fn default_52() -> Self {
+        Self::of_kind(DeckKind::Standard, 0)
+    }
+
+    /// Returns an unshuffled `Deck` of the cards in `kind`, followed by `jokers` jokers, alternately red and black.
+    pub fn of_kind(kind: DeckKind, jokers: usize) -> Self {
+        let cards = all::<Card>()
+            .filter(|card| kind.contains(card.value()))
+            .chain((0..jokers).map(|i| Card::joker(i % 2 == 0)));
         Self {
-            cards: all::<Card>().collect::<VecDeque<_>>(),
+            cards: cards.collect(),
         }
     }
 
@@ -41,9 +63,9 @@ impl Deck {
         }
     }
 
-    /// Randomizes the order of `Card`s in `self`.
-    pub fn shuffle(&mut self) {
-        self.cards.make_contiguous().shuffle(&mut thread_rng());
+    /// Randomizes the order of `Card`s in `self`, using `rng`.
+    pub fn shuffle<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+        self.cards.make_contiguous().shuffle(rng);
     }
 
     /// Returns a reference to `self.cards`.
@@ -56,14 +78,19 @@ impl Deck {
         self.cards.len()
     }
 
-    /// Returns the top (front) `Card` of the deck.
-    pub fn top(&self) -> Card {
-        self[0]
+    /// Returns the top (front) `Card` of the deck, or `None` if it's empty.
+    pub fn top(&self) -> Option<Card> {
+        self.cards.front().copied()
+    }
+
+    /// Returns the bottom (back) `Card` of the deck, or `None` if it's empty.
+    pub fn bottom(&self) -> Option<Card> {
+        self.cards.back().copied()
     }
 
-    /// Returns the bottom (back) `Card` of the deck.
-    pub fn bottom(&self) -> Card {
-        self[self.size() - 1]
+    /// Returns the `Card` at `index` from the top, or `None` if the deck isn't that big. Unlike indexing, this never panics.
+    pub fn get(&self, index: usize) -> Option<Card> {
+        self.cards.get(index).copied()
     }
 
     /// Pushes `card` to the top of the deck.
@@ -94,47 +121,61 @@ impl Deck {
         }
     }
 
-    /// Attempts to deal `amt` cards to `player`'s hand. If there aren't enough cards in the deck, it returns `Err` with the number of cards it didn't deal.
-    fn deal_fallible(&mut self, amt: usize, player: &mut Player) -> Result<Vec<Card>, usize> {
-        if self.size() == 0usize {
-            return Err(amt);
-        }
-
+    /// Attempts to deal `amt` cards to `player`'s hand, and returns them. If there aren't enough cards in the deck, nothing is dealt and it returns `Err(DeckError::NotEnoughCards)`.
+    pub fn try_deal(&mut self, amt: usize, player: &mut Player) -> DeckResult<Vec<Card>> {
         if amt > self.size() {
-            let size = self.size();
-            player.mut_hand().extend(self.cards.drain(0..));
-            return Err(amt - size);
+            return Err(DeckError::NotEnoughCards {
+                wanted: amt,
+                left: self.size(),
+            });
         }
 
-        let drawn: Vec<Card> = self.cards.drain(0..amt).collect();
-        player.mut_hand().extend(drawn.iter());
-        Ok(drawn)
+        Ok(self.deal(amt, player))
     }
 
-    /// This deals cards, just like `deal_fallible`, but will append a new deck to the end if it cannot deal `amt` cards.
+    /// This deals cards, just like `try_deal`, but if there aren't `amt` cards in the deck, it deals every card that's left instead of failing. Returns the cards dealt.
+    ///
+    /// Nothing is added to the deck, so it's up to whoever owns it to refill it first (see `Game::refill_draw_pile`).
     pub fn deal(&mut self, amt: usize, player: &mut Player) -> Vec<Card> {
-        self.check_size_and_append(amt);
-        self.deal_fallible(amt, player).unwrap()
+        let amt = amt.min(self.size());
+        let drawn: Vec<Card> = self.cards.drain(0..amt).collect();
+        player.mut_hand().extend(drawn.iter());
+        drawn
     }
 
-    /// Prepends `amt` cards into `deck`. If `amt` is larger than `self.size()`, it still adds as many as it can (emptying this deck), but then it also returns `Err` with the number of cards it did not prepend.
-    pub fn inject(&mut self, amt: usize, deck: &mut Deck) -> Result<(), usize> {
-        if amt > self.size() {
-            let size = self.size();
-            self.inject(self.size(), deck).unwrap();
-            return Err(amt - size);
-        }
+    /// Prepends `amt` cards into `deck`. If `amt` is larger than `self.size()`, it still adds as many as it can (emptying this deck), but then it also returns `Err(DeckError::NotEnoughCards)`.
+    pub fn inject(&mut self, amt: usize, deck: &mut Deck) -> DeckResult<()> {
+        let left = self.size();
+        deck.prepend(self.cards.drain(0..amt.min(left)));
 
-        deck.prepend(self.cards.drain(0..amt));
-        Ok(())
+        if amt > left {
+            Err(DeckError::NotEnoughCards { wanted: amt, left })
+        } else {
+            Ok(())
+        }
     }
 
-    /// Checks size of `pile`. If it's smaller than `cmp`, then it appends a randomized `Deck`.
-    pub fn check_size_and_append(&mut self, cmp: usize) {
+    /// Checks size of `pile`. If it's smaller than `cmp`, then it appends a copy of `deck` randomized with `rng`. Returns whether it had to.
+    pub fn check_size_and_append<R: Rng + ?Sized>(
+        &mut self,
+        cmp: usize,
+        deck: &Deck,
+        rng: &mut R,
+    ) -> bool {
         if self.size() < cmp {
-            let mut another = Deck::default_52();
-            another.shuffle();
+            let mut another = deck.clone();
+            another.shuffle(rng);
             self.append(another.into_iter());
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Removes every card from index `at` onwards (towards the bottom), and returns them as a new deck. If the deck isn't that big, the new deck is empty.
+    pub fn split_off(&mut self, at: usize) -> Deck {
+        Self {
+            cards: self.cards.split_off(at.min(self.size())),
         }
     }
 
@@ -143,6 +184,36 @@ impl Deck {
     }
 }
 
+impl DeckKind {
+    /// Returns whether decks of this kind have cards of value `value`. Jokers are never part of the kind, they're added separately.
+    pub fn contains(&self, value: Value) -> bool {
+        match self {
+            Self::Standard => value != Value::Joker,
+            Self::Piquet => value == Value::Ace || (7..=13).contains(&value.count()),
+            Self::Euchre => value == Value::Ace || (9..=13).contains(&value.count()),
+        }
+    }
+
+    /// Returns the number of cards in a deck of this kind, without jokers.
+    pub fn size(&self) -> usize {
+        all::<Value>().filter(|&value| self.contains(value)).count() * 4
+    }
+}
+
+impl FromStr for DeckKind {
+    type Err = ();
+
+    /// Parses a kind of deck from the command line format: `standard`, `piquet` or `euchre`, or how many cards it has.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "standard" | "52" => Ok(Self::Standard),
+            "piquet" | "32" => Ok(Self::Piquet),
+            "euchre" | "24" => Ok(Self::Euchre),
+            _ => Err(()),
+        }
+    }
+}
+
 impl IntoIterator for Deck {
     type Item = Card;
     type IntoIter = std::collections::vec_deque::IntoIter<Self::Item>;
@@ -178,6 +249,7 @@ impl From<Vec<Card>> for Deck {
 impl Index<usize> for Deck {
     type Output = Card;
 
+    /// Returns the card at `index` from the top. Panics if the deck isn't that big, see `Deck::get` for a version that doesn't.
     fn index(&self, index: usize) -> &Self::Output {
         &self.cards[index]
     }
@@ -188,3 +260,26 @@ impl IndexMut<usize> for Deck {
         &mut self.cards[index]
     }
 }
+
+/// The ways taking cards from a deck can fail.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeckError {
+    /// `wanted` cards were asked for, but there were only `left` in the deck.
+    NotEnoughCards { wanted: usize, left: usize },
+}
+
+impl std::fmt::Display for DeckError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotEnoughCards { wanted, left: 0 } => {
+                write!(f, "Wanted {wanted} cards from an empty deck!")
+            }
+            Self::NotEnoughCards { wanted, left } => {
+                write!(f, "Wanted {wanted} cards from a deck with only {left}!")
+            }
+        }
+    }
+}
+impl std::error::Error for DeckError {}
+
+pub type DeckResult<T> = Result<T, DeckError>;
@@ -6,124 +6,195 @@
  * 
  */
 
-use crate::card::{Card, Suit, Value};
+use crate::card::Card;
 use crate::deck::Deck;
+use crate::interface::{MistakeReport, Move, Notice, PlayerInterface};
+use crate::journal::{EventSink, GameEvent, Journal};
 use crate::player::Player;
-use crate::render::engine::{RenderResult, Screen};
-use crate::render::play_more_confirm_screen::PlayMoreConfirmScreen;
-use crate::render::play_screen::*;
-use crate::rule::priority::{ActionOption, Priority};
-use crate::rule::{rule_map::RuleMap, Action, Event, Rule};
-use serde::Serialize;
-use tinytemplate::TinyTemplate;
+use crate::render::engine::RenderResult;
+use crate::render::play_screen::RuleActionResult;
+use crate::rule::dsl::{self, DEFAULT_RULES};
+use crate::rule::legality::PlayPolicy;
+use crate::rule::priority::{ActionOption, IncompletePriority, Priority};
+use crate::rule::{self, rule_map::RuleMap, Action, Rule, RuleChange, Side, Situation, Trigger};
+use crate::view::PlayerView;
+use enum_iterator::all;
+use rand::{thread_rng, Rng, SeedableRng};
+use rand_chacha::ChaCha12Rng;
+use serde::{Deserialize, Serialize};
+use std::fs::File;
+use std::io::{BufReader, BufWriter};
+use std::path::Path;
+
+pub mod config;
+pub mod invariant;
+
+use config::{GameConfig, StartingPlayer};
+use invariant::{CardCount, CardMismatch};
+
+/// Mixed into the game's seed to get the seed for shuffling the priority, so it's different from the game's.
+const PRIORITY_SEED: u64 = 0x9e37_79b9_7f4a_7c15;
+
+/// How many changes to the rules a winner can have refused before they lose their chance to make one. Without it, an interface that only ever asks for changes that can't be made (eg. a bot once its rules are all taken) would be asked forever.
+pub const MAX_RULE_TRIES: usize = 10;
 
 /// The game control struct, representing the game itself, and containing all game info and state transitions.
-#[derive(Debug)]
+///
+/// The whole state of the game can be serialized, so a game can be saved with `Game::save` and resumed with `Game::load`.
+#[derive(Debug, Serialize, Deserialize)]
 pub struct Game {
     players: Vec<Player>,
     draw_pile: Deck,
     used_pile: Deck,
     order: Order,
     player_index: usize,
+    round_n: usize,
     round_over: bool,
     priority: Priority, // Order to apply rules
     rule_map: RuleMap,
+    seed: u64,
+    rng: ChaCha12Rng, // Source of all randomness in the game, seeded with `seed` (the same generator as `rand::rngs::StdRng`, but serializable)
+    journal: Journal,
+    #[serde(default)]
+    config: GameConfig,
+    #[serde(skip)]
+    cards: CardCount, // The cards there should be in the game (see `Game::check_cards`)
 }
 
 impl Game {
-    /// Creates a new `Game` instance given a vector of `Players`.
+    /// Creates a new `Game` instance given a vector of `Players`, with a random seed.
     pub fn new(players: Vec<Player>) -> Self {
+        Self::with_seed(players, thread_rng().gen())
+    }
+
+    /// Creates a new `Game` instance given a vector of `Players`, where all of the randomness (shuffling, etc.) comes from `seed`.
+    ///
+    /// The same seed with the same inputs always plays out the same game.
+    pub fn with_seed(players: Vec<Player>, seed: u64) -> Self {
+        Self::with_config(players, seed, GameConfig::default())
+    }
+
+    /// Creates a new `Game` instance like `Game::with_seed`, set up with `config` instead of the usual game of Mao.
+    pub fn with_config(players: Vec<Player>, seed: u64, config: GameConfig) -> Self {
         let mut game = Self {
             players,
             draw_pile: Deck::default_52(),
             used_pile: Deck::empty(),
             order: Order::Forward,
             player_index: 0,
+            round_n: 0,
             round_over: false,
             rule_map: RuleMap::default(),
             priority: Priority::default(),
+            seed,
+            rng: ChaCha12Rng::seed_from_u64(seed),
+            journal: Journal::new(),
+            config,
+            cards: CardCount::default(),
         };
-
-        // Checks for 25 here because 1 card is put in used_pile
-        Deck::check_size_and_append(&mut game.draw_pile, 25);
-        game.draw_pile.inject(1usize, &mut game.used_pile).unwrap();
+        game.draw_pile = game.new_draw_pile();
+        // A deck always has cards, but if it didn't, the used pile would just start empty.
+        game.draw_pile.inject(1usize, &mut game.used_pile).ok();
+        game.cards = game.card_counts();
 
         // Prepares default rules
-        // Consecutive 7s rule isn't included here, so has to be done elsewhere
-        game.add_rule(Rule::new(
-            Event::ValuePlayed(Value::Seven),
-            Action::Say(String::from("have a nice day")),
-        ))
-        .unwrap();
-
-        game.add_rule(Rule::new(Event::ValuePlayed(Value::Ace), Action::Skip))
-            .unwrap();
-
-        game.add_rule(Rule::new(Event::ValuePlayed(Value::Two), Action::Repeat))
-            .unwrap();
+        if game.config.default_rules {
+            let rules = dsl::parse_rules(DEFAULT_RULES).expect("the default rules should be valid");
+            game.use_rules(rules)
+                .expect("the default rules shouldn't conflict");
+        }
+        game.rule_map.set_play_policy(game.config.play_policy);
 
-        game.add_rule(Rule::new(Event::ValuePlayed(Value::Eight), Action::Reverse))
-            .unwrap();
+        game
+    }
 
-        // Ace rule
-        game.add_rule(Rule::new(
-            Event::SuitPlayed(Suit::Spades),
-            Action::Say(String::from("{value} of spades")),
-        ))
-        .unwrap();
+    /// Creates a new `Game` instance like `Game::with_seed`, where the rules' actions are applied in the order of `priority` instead of the default one. `Priority::shuffled` makes a random one.
+    ///
+    /// The default priority is kept if `priority` isn't complete (see `Priority::is_complete`).
+    pub fn with_priority(players: Vec<Player>, seed: u64, priority: Priority) -> Self {
+        let mut game = Self::with_seed(players, seed);
+        let _ = game.set_priority(priority);
 
         game
     }
 
-    pub fn play(&mut self) -> RenderResult<Vec<usize>> {
+    /// Plays rounds until the players don't want to play anymore, asking `interface` for all of the players' decisions. Returns a result, with an `Ok` value holding the index of the winner of each round.
+    pub fn play(&mut self, interface: &mut dyn PlayerInterface) -> RenderResult<Vec<usize>> {
         let mut winners = vec![];
 
-        let mut round = 1;
-
         loop {
-            PlayScreen::NewRound { round_n: round }.render_then_wait(Some(self))?;
+            // A loaded game may be in the middle of a round already.
+            if !self.round_in_progress() {
+                self.start_round();
+                interface.notify(
+                    &self.public_view(),
+                    Notice::NewRound {
+                        round_n: self.round_n,
+                    },
+                )?;
+            }
 
-            let winner = self.round()?;
+            let winner = self.round(interface)?;
             winners.push(winner);
 
             // Ask if they want to play again.
-            if !self.confirm_next_round()? {
+            if !interface.confirm_next_round(&self.public_view())? {
                 break;
             }
 
-            // Allow winner to add or remove a rule
-            self.create_rule(winner)?;
-
-            round += 1;
+            // Allow winner to add or remove a rule, and then to reorder the priority.
+            self.create_rule(winner, interface)?;
+            self.reorder_priority(winner, interface)?;
         }
 
+        // Nobody's playing anymore, so the rules don't have to be secret.
+        let rules = self.rule_map.rules().cloned().collect();
+        interface.notify(&self.public_view(), Notice::RulesRevealed(rules))?;
+
         Ok(winners)
     }
 
-    /// Goes through gameplay loop until a player wins, returns a result, with an `Ok` value holding the index of the winner.
-    pub fn round(&mut self) -> RenderResult<usize> {
+    /// Resets the piles, hands and order of play, then deals a new round.
+    pub fn start_round(&mut self) {
+        self.round_n += 1;
+
         // Resets values
         self.used_pile.clear();
-        self.draw_pile = Deck::default_52();
+        self.draw_pile = self.new_draw_pile();
         self.order = Order::Forward;
-        self.player_index = 0;
+        self.player_index = self.starting_player();
         self.round_over = false;
+        self.journal.record(GameEvent::RoundStarted {
+            round: self.round_n,
+        });
       
         // Clear player's hands.
         for player in self.players.iter_mut() {
             player.clear_hand();
         }
+        self.cards = self.card_counts();
 
-        // Deals 7 cards to each player
-        // If there are less than 24 cards left, it adds another 52 cards to the deck
-        self.draw_pile.shuffle();
-        for player in self.players.iter_mut() {
-            self.draw_pile.deal(7, player);
+        // Deals `hand_size` cards to each player
+        // If there are less than `refill_below` cards left after, the used pile is shuffled back in, or another deck is added
+        self.draw_pile.shuffle(&mut self.rng);
+        for player in 0..self.players.len() {
+            self.deal_to(player, self.config.hand_size);
         }
 
-        // Checks for 25 here because 1 card is put in used_pile
-        Deck::check_size_and_append(&mut self.draw_pile, 25);
-        self.draw_pile.inject(1usize, &mut self.used_pile).unwrap();
+        // Checks for `refill_below` (and at least 1) here because 1 card is put in used_pile
+        // The refill always leaves a card, but if it didn't, the used pile would just start empty.
+        self.refill_draw_pile(self.config.refill_below.max(1));
+        self.draw_pile.inject(1usize, &mut self.used_pile).ok();
+        self.debug_check_cards("dealing a new round");
+    }
+
+    /// Goes through gameplay loop until a player wins, returns a result, with an `Ok` value holding the index of the winner.
+    ///
+    /// If a round isn't already in progress, a new one is started first.
+    pub fn round(&mut self, interface: &mut dyn PlayerInterface) -> RenderResult<usize> {
+        if !self.round_in_progress() {
+            self.start_round();
+        }
 
         /*
           Loop through each player
@@ -134,52 +205,68 @@ impl Game {
                 Draws card from pile <in Player>
         */
         while !self.round_over {
-            self.screen_next_player()?;
+            let turn_player = self.player_index;
+            interface.notify(&self.public_view(), Notice::NewTurn)?;
             /*
               `did_draw` is true if the player drew a card
               `action_card` is the target card of the action
                 - the card they drew if they drew a card, or
                 - the card they played when the played a card
             */
+            let first_turn = self.is_first_turn();
             let mut incorrect_play = false;
-            let (did_draw, action_card) = if let Some(play) = self.screen_request_card_play()? {
-                // Player played a card out of their hand.
-                let action_card = self.current_player().hand()[play];
-                incorrect_play = !self.validate_card_played(action_card);
-                self.players[self.player_index].play_card(play, &mut self.used_pile);
-                (false, action_card)
-            } else {
-                // Player drew a card.
-                let card = self.players[self.player_index].draw(&mut self.draw_pile);
-                (true, card)
+            let (did_draw, action_card) = match self.request_move(interface)? {
+                Move::Play(play) => {
+                    // Player played a card out of their hand.
+                    let action_card = self.current_player().hand()[play];
+                    incorrect_play = !self.validate_card_played(action_card);
+                    self.players[self.player_index].play_card(play, &mut self.used_pile);
+                    self.journal.record(GameEvent::CardPlayed {
+                        player: self.player_index,
+                        card: action_card,
+                        legal: !incorrect_play,
+                    });
+                    self.debug_check_cards("playing a card");
+                    (false, action_card)
+                }
+                Move::Draw => {
+                    // Player drew a card.
+                    let Some(&card) = self.deal_to(self.player_index, 1).first() else {
+                        // There was nothing to draw, even after refilling the draw pile, so the turn just passes.
+                        self.next_player();
+                        continue;
+                    };
+                    self.journal.record(GameEvent::CardDrawn {
+                        player: self.player_index,
+                        card: Some(card),
+                    });
+                    self.debug_check_cards("drawing a card");
+                    (true, card)
+                }
+                Move::Save(_) => unreachable!("saving is done while asking for the move"),
             };
 
             // Player is given a chance to speak
-            let quotes = self.screen_request_turn_speak(did_draw, action_card)?;
+            let quotes =
+                interface.request_speech(&self.view(self.player_index), did_draw, action_card)?;
 
-            println!("Qs: {:?}", &quotes);
-
-            // Check for "thank you" if they drew a card.
-            if did_draw {
-                let mistakes = self.check_quotes(&quotes, &vec![String::from("thank you")]);
-
-                println!("Mistakes: {:?}", mistakes); // Log check
-
-                self.mistake_screen(mistakes, false, None)?;
+            // The rules for what they did, and for the round starting if it's the first turn.
+            let mut triggers = vec![if did_draw {
+                Trigger::CardDrawn
             } else {
-                println!("{}", self.used_pile[0]);
-
-                let mut reqs = vec![];
-
-                if self.players[self.player_index].num_cards() == 0 {
-                    reqs.push(String::from("mao"));
-                }
-
-                let (mistakes, _idx) = self.apply_rules(&quotes, reqs, incorrect_play)?;
-                self.mistake_screen(mistakes, incorrect_play, Some(action_card))?;
+                Trigger::CardPlayed
+            }];
+            if first_turn {
+                triggers.push(Trigger::RoundStarted);
             }
-
-            if self.current_player().num_cards() == 0 {
+            self.apply_rules(&triggers, action_card, &quotes, incorrect_play, interface)?;
+
+            // It's usually the player who just went that's out of cards, but rules can skip past them, or move cards between players.
+            let winner = std::iter::once(turn_player)
+                .chain(0..self.num_players())
+                .find(|&i| self.players[i].num_cards() == 0);
+            if let Some(winner) = winner {
+                self.player_index = winner;
                 self.round_over = true;
                 break;
             }
@@ -188,130 +275,81 @@ impl Game {
         }
 
         let winner = self.player_index;
-        self.screen_win(winner)?;
+        self.journal.record(GameEvent::RoundWon {
+            round: self.round_n,
+            player: winner,
+        });
+        let art = self.rng.gen();
+        interface.notify(&self.public_view(), Notice::Win { winner, art })?;
         Ok(winner)
     }
 
-    pub fn confirm_next_round(&self) -> RenderResult<bool> {
-        Ok(loop {
-            let res = PlayMoreConfirmScreen.render_then_input(Some(&self))?;
-            match res.trim().to_lowercase().as_str() {
-                "y" | "yes" | "ok" | "play" | "continue" => break true,
-                "n" | "no" | "quit" | "end" | "stop" => break false,
-                _ => continue,
-            }
-        })
-    }
+    /// Asks `winner` for a change to the rules until they make one that can be made: adding a rule, or removing or modifying one they describe. After `MAX_RULE_TRIES` refused changes, the rules are left as they are.
+    pub fn create_rule(
+        &mut self,
+        winner: usize,
+        interface: &mut dyn PlayerInterface,
+    ) -> RenderResult<()> {
+        for _ in 0..MAX_RULE_TRIES {
+            // New rules are marked as the winner's here, rather than trusting the interface to.
+            let change = match interface.request_rule(&self.view(winner), winner)? {
+                RuleChange::Add(rule) => RuleChange::Add(rule.made_by(winner, self.round_n)),
+                RuleChange::Modify { old, new } => RuleChange::Modify {
+                    old,
+                    new: new.made_by(winner, self.round_n),
+                },
+                remove => remove,
+            };
 
-    pub fn create_rule(&mut self, winner: usize) -> RenderResult<()> {
-        loop {
-            let mut format_issue = None;
-            let event: Event = loop {
-                let event_str = PlayScreen::CreateRule {
-                    winner,
-                    format_issue,
-                    state: CreateRuleState::MakeEvent,
+            match self.change_rule(change.clone()) {
+                Ok(()) => {
+                    self.journal.record(match change {
+                        RuleChange::Add(rule) => GameEvent::RuleCreated {
+                            player: winner,
+                            rule,
+                        },
+                        RuleChange::Remove(rule) => GameEvent::RuleRemoved {
+                            player: winner,
+                            rule,
+                        },
+                        RuleChange::Modify { old, new } => GameEvent::RuleModified {
+                            player: winner,
+                            old,
+                            new,
+                        },
+                    });
+                    return Ok(());
                 }
-                .render_then_input(Some(self))?;
-
-                let (typ, data) = if let Some(x) = event_str.trim().split_once(" is ") {
-                    x
-                } else {
-                    format_issue = Some(format!("'{}' is missing ' is '", event_str.trim()));
-                    continue;
-                };
-                let typ = typ.trim().to_lowercase();
-                let data = data.trim();
-
-                if let Some(event) = match typ.as_str() {
-                    "card" => {
-                        if let Some((value_str, suit_str)) = data.split_once(" of ") {
-                            if let (Some(value), Some(suit)) =
-                                (Value::from_str(value_str), Suit::from_str(suit_str))
-                            {
-                                Some(Event::CardPlayed(Card::new(value, suit)))
-                            } else {
-                                None
-                            }
-                        } else {
-                            None
-                        }
-                    }
-                    "suit" => {
-                        if let Some(suit) = Suit::from_str(data) {
-                            Some(Event::SuitPlayed(suit))
-                        } else {
-                            None
-                        }
-                    }
-                    "value" => {
-                        if let Some(value) = Value::from_str(data) {
-                            Some(Event::ValuePlayed(value))
-                        } else {
-                            None
-                        }
-                    }
-                    _ => None,
-                } {
-                    format_issue = Some("".to_string());
-                    break event;
-                } else {
-                    format_issue = Some(format!("'{}' is invalid for type '{}'", data, typ));
-                    continue;
+                Err(AddingRuleError::ConflictingAction) => {
+                    interface.notify(&self.public_view(), Notice::RuleExists { winner })?;
                 }
-            };
-            let action: Action = loop {
-                let action_str = PlayScreen::CreateRule {
-                    winner,
-                    format_issue,
-                    state: CreateRuleState::MakeAction,
+                Err(AddingRuleError::NoSuchRule) => {
+                    interface.notify(&self.public_view(), Notice::NoSuchRule { winner })?;
                 }
-                .render_then_input(Some(self))?;
-
-                match action_str.trim().to_lowercase().as_str() {
-                    "draw" => break Action::Draw,
-                    "repeat" => break Action::Repeat,
-                    "reverse" => break Action::Reverse,
-                    "skip" => break Action::Skip,
-                    s => {
-                        if s.starts_with("say ") {
-                            // `s` is trimmed, so with the ending whitespace in "say ", it's known there will be non whitespace after that, and so it can safely be assumed that `quote` will be non-empty
-                            let quote = (&s["say ".len()..]).trim().to_string();
-
-                            if quote.contains(".") {
-                                format_issue = Some(format!("'{}' may not contain '.'", &quote));
-                                continue;
-                            }
-
-                            dbg!(&quote);
-                            break Action::Say(quote);
-                        } else {
-                            format_issue = Some(format!("'{}' is invalid", s));
-                        }
-                    }
+                Err(AddingRuleError::InvalidAction) => {
+                    unreachable!("every action has a key in the rule map");
                 }
-            };
+            }
+        }
 
-            if let Err(error) = self.add_rule(Rule::new(event, action)) {
-                // TODO handle other kinds of error, (though I do beleive this is the only one that comes up)
-                match error {
-                    AddingRuleError::ConflictingAction => {
-                        PlayScreen::CreateRule {
-                            format_issue: None,
-                            winner,
-                            state: CreateRuleState::RuleExists,
-                        }
-                        .render_then_wait(Some(&self))?;
-                    }
-                    AddingRuleError::InvalidAction => {
-                        panic!("SHOULD NOT HAPPEN");
-                    }
-                }
-            } else {
-                // all good, break
-                break;
+        Ok(())
+    }
+
+    /// Asks `winner` if they want to change the order the rules' actions are applied in, and changes it if they do. A priority without every action exactly once is ignored, since it would stop some rules from ever happening.
+    pub fn reorder_priority(
+        &mut self,
+        winner: usize,
+        interface: &mut dyn PlayerInterface,
+    ) -> RenderResult<()> {
+        if let Some(priority) = interface.request_priority(&self.view(winner), winner)? {
+            if priority != self.priority && self.set_priority(priority.clone()).is_ok() {
+                self.journal.record(GameEvent::PriorityChanged {
+                    player: winner,
+                    priority,
+                });
             }
         }
+
         Ok(())
     }
 
@@ -319,7 +357,14 @@ impl Game {
     ///
     /// Follows the direction of play. Eg. if the game is moving in the `Backward`s direction, then it goes backwards.
     pub fn next_player(&mut self) -> &Player {
-        self.player_index = match self.order {
+        self.player_index = self.next_index();
+
+        &self.players[self.player_index]
+    }
+
+    /// Returns the index of the player after the current one, following the direction of play.
+    fn next_index(&self) -> usize {
+        match self.order {
             Order::Forward => {
                 if self.player_index == self.num_players() - 1 {
                     0
@@ -335,11 +380,15 @@ impl Game {
                     self.player_index - 1
                 }
             }
-        };
-
-        println!("NEXT PLAYER: {}", self.player_index);
+        }
+    }
 
-        &self.players[self.player_index]
+    /// Returns the index of the player on `side` of the current one.
+    fn beside(&self, side: Side) -> usize {
+        match side {
+            Side::Left => (self.player_index + 1) % self.num_players(),
+            Side::Right => (self.player_index + self.num_players() - 1) % self.num_players(),
+        }
     }
 
     pub fn prev_player(&mut self) {
@@ -349,19 +398,17 @@ impl Game {
     }
 
     /// Checks `quotes` to see if they all follow the required values for `reqs` and returns the mistakes made. If each `quote` can be matched with a `req`, then it returns `None`, otherwise it returns `Some` with a tuple with the "Incorrect use of _"s first and "Failure to say _"s second: `Some((Incorrects, Failures))`.
-    pub fn check_quotes(&self, quotes: &Vec<String>, reqs: &Vec<String>) -> Option<Mistakes> {
+    pub fn check_quotes(&self, quotes: &[String], reqs: &[String]) -> Option<Mistakes> {
         if quotes.is_empty() {
             return if reqs.is_empty() {
                 None
             } else {
-                Some((vec![], reqs.clone()))
+                Some((vec![], reqs.to_vec()))
             };
         }
 
-        let mut new_quotes = quotes.clone(); // Remaining quotes are "Incorrect Use of _."
-        let mut new_reqs = reqs.clone(); // Remaining reqs are "Failure to Say _."
-
-        println!("INSIDE CHECK QUOTES");
+        let mut new_quotes = quotes.to_vec(); // Remaining quotes are "Incorrect Use of _."
+        let mut new_reqs = reqs.to_vec(); // Remaining reqs are "Failure to Say _."
 
         // Loop through each requirement
         let mut i = 0i32;
@@ -389,167 +436,394 @@ impl Game {
         }
     }
 
+    /// Replaces every rule in the game with `rules`, eg. from a rules file (see `rule::dsl`). The play policy is kept.
+    ///
+    /// If any of `rules` conflict (see `Game::add_rule`), an error is returned and the rules are left as they were.
+    pub fn use_rules(&mut self, rules: Vec<Rule>) -> AddingRuleResult<()> {
+        let old = self.rule_map.clone();
+        self.clear_rules();
+
+        for rule in rules {
+            if let Err(err) = self.add_rule(rule) {
+                self.rule_map = old;
+                return Err(err);
+            }
+        }
+        Ok(())
+    }
+
+    /// Removes every rule from the game. The play policy is kept.
     pub fn clear_rules(&mut self) {
-        // for (option, _) in self.rule_map.iter
+        for option in all::<ActionOption>() {
+            self.rule_map.empty_vec(option);
+        }
+    }
+
+    /// Makes `change` to the rules. If it can't be made, an error is returned and the rules are left as they were: a rule being added (or modified into) conflicts with another one (see `Game::add_rule`), or there's no rule to remove or modify.
+    pub fn change_rule(&mut self, change: RuleChange) -> AddingRuleResult<()> {
+        match change {
+            RuleChange::Add(rule) => self.add_rule(rule),
+            RuleChange::Remove(rule) => self.remove_rule(rule).map(|_| ()),
+            RuleChange::Modify { old, new } => {
+                // The old rule is out of the way first, so the new one doesn't conflict with what it's replacing.
+                let old = self.remove_rule(old)?;
+                let result = self.add_rule(new);
+                if result.is_err() {
+                    self.rule_map.push(old);
+                }
+                result
+            }
+        }
+    }
+
+    /// Removes the rule with the same event and action as `rule` from `self.rule_map`, and returns it. Returns `Err(AddingRuleError::NoSuchRule)` if there isn't one.
+    pub fn remove_rule(&mut self, rule: Rule) -> AddingRuleResult<Rule> {
+        self.rule_map
+            .remove(rule)
+            .ok_or(AddingRuleError::NoSuchRule)
     }
 
-    /// Attempts to add (or remove) a rule from `self.rule_map`. Returns Ok(()) if it works, Err(()) if the rule already exists, or there is a conflicting rule.
-    /// A conflicting rule is a rule that has the same action
+    /// Attempts to add a rule to `self.rule_map`. Returns Ok(()) if it works, or an error if the rule already exists, or there is a conflicting rule.
+    /// A conflicting rule is a rule that has the same action, and already happens for every card `rule` would. For `Say` rules, it's enough for them to happen for any of the same cards, since it would have to be said twice.
     pub fn add_rule(&mut self, rule: Rule) -> AddingRuleResult<()> {
         let action_option = ActionOption::from(rule.action());
 
         // Checks if `action_option` is a valid key
         // If so, sets `rules` to the vector of rules for that option.
-        // All actions for each rule in `rules` are the same for one pass of the loop
-        if let Some(rules) = self.rule_map.get(&action_option) {
-            if rules.is_empty() {
-                self.rule_map.push_to(action_option, rule).unwrap();
-                return Ok(());
-            }
-
-            // Gets all options of values in corresponding vec.
-            let events = rules.iter().map(|rule| rule.event()).collect::<Vec<_>>();
-
-            // If the event that triggers the action is unique, push it to the vec.
-            if !events.contains(&rule.event()) {
-                self.rule_map.push_to(action_option, rule).unwrap();
-                Ok(())
-            // Otherwise, `rule`'s event is not unique.
-            // If `rule`'s action is `Say(msg)`, check if `msg` is unique.
-            } else if action_option == ActionOption::Say {
-                // If not, return error.
-                if rules
-                    .iter()
-                    .map(|r| r.action())
-                    .collect::<Vec<_>>()
-                    .contains(&rule.action())
-                {
-                    Err(AddingRuleError::ConflictingAction)
-                // If so, push it; requiring many messages to be written by player.
+        let rules = self
+            .rule_map
+            .get(&action_option)
+            .ok_or(AddingRuleError::InvalidAction)?;
+
+        // Only rules with the exact same action can conflict, `Say` rules with different messages are both required.
+        let event = rule.event();
+        let conflicting = rules
+            .iter()
+            .filter(|existing| existing.action() == rule.action())
+            .any(|existing| {
+                if action_option == ActionOption::Say {
+                    existing.event().overlaps(&event)
                 } else {
-                    self.rule_map.push_to(action_option, rule);
-                    Ok(())
+                    existing.event().covers(&event)
                 }
-            // Otherwise, return error.
-            } else {
-                Err(AddingRuleError::ConflictingAction)
-            }
-        // Otherwise, return error.
+            });
+
+        if conflicting {
+            Err(AddingRuleError::ConflictingAction)
         } else {
-            Err(AddingRuleError::InvalidAction)
+            self.rule_map.push_to(action_option, rule).unwrap();
+            Ok(())
         }
     }
 
-    /// Applies the rules in order of the priority. Returns a tuple of mistakes and the player index of the player who just played.
+    /// Applies the rules set off by `triggers` after the current player's turn. What the rules require the player to say is checked against `quotes` first, and the player is penalized for any mistakes. Then, unless the player played an illegal card (`was_invalid`), the actions of the rules are applied in order of the priority.
     ///
-    /// `req_msgs` is the initial reqs to check against `quotes`.
+    /// `card` is the card the player played or drew, which the `{card}`, `{value}` and `{suit}` of `Say` rules are filled in with.
     pub fn apply_rules(
         &mut self,
-        quotes: &Vec<String>,
-        mut req_msgs: Vec<String>,
+        triggers: &[Trigger],
+        card: Card,
+        quotes: &[String],
         was_invalid: bool,
-    ) -> RenderResult<(Option<Mistakes>, usize)> {
-        let idx = self.player_index;
+        interface: &mut dyn PlayerInterface,
+    ) -> RenderResult<()> {
+        let mut triggers = triggers.to_vec();
 
-        let priority = self.priority.clone();
-        for option in priority.iter() {
-            // Gets all the rules that apply for the top card of `used_pile`.
-            let rules = if let Some(rules) = self.rule_map.get(option) {
-                rules
-            } else {
-                continue;
-            }
-            .iter()
-            .filter(|rule| rule.event().arg_matches(self.used_pile[0]))
-            .collect::<Vec<_>>();
+        // The order being reversed has to be known about now, since it could need something saying too.
+        if !was_invalid && self.triggered_actions(&triggers).contains(&Action::Reverse) {
+            triggers.push(Trigger::Reversed);
+        }
+
+        // Everything is matched before any penalties or actions, so the rules all see the game as it was after the turn.
+        let actions = self.triggered_actions(&triggers);
+        let reqs = self.required_quotes(&triggers, card);
+
+        let mistakes = self.check_quotes(quotes, &reqs);
+        let fails = mistakes.as_ref().map_or(vec![], |(_, fails)| fails.clone());
+        self.blame_rules(&triggers, card, &fails, was_invalid);
+        self.mistake_screen(mistakes, was_invalid, Some(card), interface)?;
+
+        if !was_invalid {
+            self.apply_actions(&actions, interface)?;
+        }
+        Ok(())
+    }
 
-            // If no rules with this action, continue to next action.
-            if rules.is_empty() {
-                continue;
+    /// Returns the rules with `option`'s action that are set off by any of `triggers` right now, for the current player.
+    fn rules_for(&self, triggers: &[Trigger], option: &ActionOption) -> Vec<Rule> {
+        let played: Vec<Card> = self.used_pile.cards().iter().copied().collect();
+        let cards_left = self.current_player().num_cards();
+
+        self.rule_map
+            .get(option)
+            .into_iter()
+            .flatten()
+            .filter(|rule| {
+                triggers.iter().any(|&trigger| {
+                    rule.event().matches(&Situation {
+                        trigger,
+                        played: &played,
+                        cards_left,
+                    })
+                })
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// Counts a penalty against each rule the current player is about to be penalized for breaking: the `Say` rules set off by `triggers` whose message (filled in for `card`) is in `fails`, and if `card_error`, the `Forbid` rules that made the card on top of the used pile illegal.
+    fn blame_rules(
+        &mut self,
+        triggers: &[Trigger],
+        card: Card,
+        fails: &[String],
+        card_error: bool,
+    ) {
+        let mut blamed: Vec<Rule> = self
+            .rules_for(triggers, &ActionOption::Say)
+            .into_iter()
+            .filter(|rule| match rule.action() {
+                Action::Say(msg) => fails.contains(&Game::parse_message(msg, card)),
+                _ => false,
+            })
+            .collect();
+        if card_error {
+            blamed.extend(self.rules_for(&[Trigger::CardPlayed], &ActionOption::Forbid));
+        }
+
+        for rule in &blamed {
+            self.rule_map.blame(rule);
+        }
+    }
+
+    /// Returns everything the current player has to say because of `triggers`, filled in for `card`.
+    fn required_quotes(&self, triggers: &[Trigger], card: Card) -> Vec<String> {
+        self.rules_for(triggers, &ActionOption::Say)
+            .into_iter()
+            .filter_map(|rule| match rule.action() {
+                Action::Say(msg) => Some(Game::parse_message(msg, card)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Returns the actions of the rules set off by `triggers` for the current player, in order of the priority. Rules with the same action only make it happen once, but different amounts add up, eg. "draw" and "draw 2" make the player draw 3 cards.
+    fn triggered_actions(&self, triggers: &[Trigger]) -> Vec<Action> {
+        let mut actions = vec![];
+        for option in self.priority.iter() {
+            for rule in self.rules_for(triggers, option) {
+                if !actions.contains(&rule.action()) {
+                    actions.push(rule.action());
+                }
             }
+        }
+        actions
+    }
+
+    /// Applies `actions` (from `Game::triggered_actions`) for the current player, in order.
+    ///
+    /// Counts are cut down to `rule::MAX_COUNT`, since rules that weren't parsed (eg. from a save) could have any count.
+    fn apply_actions(
+        &mut self,
+        actions: &[Action],
+        interface: &mut dyn PlayerInterface,
+    ) -> RenderResult<()> {
+        let idx = self.player_index;
 
-            match (was_invalid, option) {
-                // If option is `Say`, append required msgs to vector.
-                (_, ActionOption::Say) => {
-                    req_msgs.extend(rules.iter().map(|rule| match rule.action() {
-                        Action::Say(msg) => Game::parse_message(msg, self.used_pile[0]),
-                        _ => String::new(), // Should not happen
-                    }));
+        for action in actions {
+            match action {
+                // `Say` rules are requirements, which have already been checked, and legality rules were checked when the card was played.
+                Action::Say(_) | Action::Allow | Action::Forbid => {}
+
+                Action::Draw(n) | Action::DrawNext(n) => {
+                    let who = match action {
+                        Action::DrawNext(_) => self.next_index(),
+                        _ => self.player_index,
+                    };
+                    let cards = self.deal_to(who, (*n).min(rule::MAX_COUNT) as usize);
+
+                    self.rule_invoked(
+                        idx,
+                        RuleActionResult::Draw {
+                            who,
+                            cards: cards.into_iter().map(Some).collect(),
+                        },
+                        interface,
+                    )?;
+                }
 
-                    println!("MSGS: {:?}", req_msgs);
+                Action::Pass(side) => {
+                    let from = self.player_index;
+                    let to = self.beside(*side);
+
+                    // There's nothing to pass with an empty hand.
+                    let num_cards = self.players[from].num_cards();
+                    if num_cards > 0 {
+                        let i = self.rng.gen_range(0..num_cards);
+                        let card = self.players[from].mut_hand().remove(i);
+                        self.players[to].mut_hand().push(card);
+
+                        self.rule_invoked(
+                            idx,
+                            RuleActionResult::Pass {
+                                from,
+                                to,
+                                side: *side,
+                                card: Some(card),
+                            },
+                            interface,
+                        )?;
+                    }
                 }
 
-                (false, ActionOption::Draw) => {
-                    let drawn_card = self.players[self.player_index].draw(&mut self.draw_pile);
+                Action::SwapHands => {
+                    let who = self.player_index;
+                    let with = self.next_index();
 
-                    PlayScreen::RuleInvocation(RuleActionResult::Draw(drawn_card))
-                        .render_then_wait(Some(&self))?;
+                    let hand = std::mem::take(self.players[who].mut_hand());
+                    let other = std::mem::replace(self.players[with].mut_hand(), hand);
+                    *self.players[who].mut_hand() = other;
+
+                    self.rule_invoked(idx, RuleActionResult::SwapHands { who, with }, interface)?;
                 }
 
-                (false, ActionOption::Repeat) => {
+                Action::Repeat => {
                     let who_is_repeating = self.player_index;
 
                     self.prev_player();
-                    PlayScreen::RuleInvocation(RuleActionResult::Repeat {
-                        who: who_is_repeating,
-                    })
-                    .render_then_wait(Some(&self))?;
+                    self.rule_invoked(
+                        idx,
+                        RuleActionResult::Repeat {
+                            who: who_is_repeating,
+                        },
+                        interface,
+                    )?;
                 }
 
-                (false, ActionOption::Reverse) => {
+                Action::Reverse => {
                     self.order = self.order.flip();
 
-                    PlayScreen::RuleInvocation(RuleActionResult::Reverse)
-                        .render_then_wait(Some(&self))?;
+                    self.rule_invoked(idx, RuleActionResult::Reverse, interface)?;
                 }
 
-                (false, ActionOption::Skip) => {
-                    self.next_player();
-
-                    let who_is_skipped = self.player_index; // "current" player will be skipped by next `next_player` call
-                    PlayScreen::RuleInvocation(RuleActionResult::Skip {
-                        who: who_is_skipped,
-                    })
-                    .render_then_wait(Some(&self))?;
-                } // Just do `next_player?`
-
-                _ => {}
+                Action::Skip(n) => {
+                    for _ in 0..(*n).min(rule::MAX_COUNT) {
+                        self.next_player();
+
+                        let who_is_skipped = self.player_index; // "current" player will be skipped by next `next_player` call
+                        self.rule_invoked(
+                            idx,
+                            RuleActionResult::Skip {
+                                who: who_is_skipped,
+                            },
+                            interface,
+                        )?;
+                    }
+                }
             }
+            self.debug_check_cards(format_args!("applying the action \"{}\"", action));
         }
 
-        // println!("Reqs: {}", &req_msgs[0]);
-        // Check quotes and return the player index of the player who just played
-        Ok((self.check_quotes(quotes, &req_msgs), idx))
+        Ok(())
+    }
+
+    /// Whether no one has played or drawn a card yet this round.
+    fn is_first_turn(&self) -> bool {
+        self.journal.round_events().iter().all(|event| {
+            !matches!(
+                event,
+                GameEvent::CardPlayed { .. } | GameEvent::CardDrawn { .. }
+            )
+        })
     }
 
-    fn parse_message(message: String, card: Card) -> String {
-        let mut template = TinyTemplate::new();
+    /// Deals `amt` cards from the draw pile to `player`, refilling the draw pile first if it's needed. If it can't be refilled with enough cards, only the cards there are get dealt.
+    fn deal_to(&mut self, player: usize, amt: usize) -> Vec<Card> {
+        self.refill_draw_pile(amt);
+        self.draw_pile.deal(amt, &mut self.players[player])
+    }
 
-        template.add_template("template", &message).unwrap();
+    /// Returns an unshuffled deck of the kind the game is played with, with its jokers (see `GameConfig::deck`).
+    fn new_deck(&self) -> Deck {
+        Deck::of_kind(self.config.deck, self.config.jokers)
+    }
 
-        let context = Context {
-            card: format!(
-                "{} of {}",
-                card.value().full_name(),
-                card.suit().full_name()
-            )
-            .to_lowercase()
-            .to_owned(),
-            value: format!("{}", card.value().full_name())
-                .to_lowercase()
-                .to_owned(),
-            suit: format!("{}", card.suit().full_name())
-                .to_lowercase()
-                .to_owned(),
-        };
+    /// Returns the unshuffled draw pile a round starts with, made of `config.decks` decks.
+    fn new_draw_pile(&self) -> Deck {
+        let cards: Vec<Card> = (0..self.config.decks)
+            .flat_map(|_| self.new_deck())
+            .collect();
+        Deck::from(cards)
+    }
+
+    /// Returns who goes first in the round being started, by `config.starting_player`. Must be called after `self.round_n` is moved on to the new round.
+    fn starting_player(&mut self) -> usize {
+        match self.config.starting_player {
+            StartingPlayer::First => 0,
+            StartingPlayer::Random => self.rng.gen_range(0..self.num_players()),
+            StartingPlayer::Rotate => (self.round_n - 1) % self.num_players(),
+            StartingPlayer::LastWinner => self
+                .journal
+                .events()
+                .iter()
+                .rev()
+                .find_map(|event| match event {
+                    GameEvent::RoundWon { player, .. } => Some(*player),
+                    _ => None,
+                })
+                .unwrap_or(0),
+        }
+    }
 
-        let fmt_msg = template
-            .render("template", &context)
-            .expect("Should not panic here (@ formatting the message).");
+    /// Refills the draw pile if it has less than `cmp` cards, like with a real deck: everything in the used pile but the top card is shuffled back into it. Only if that still isn't enough is a new deck added, as a last resort (see `GameConfig::add_decks`). Either is recorded.
+    fn refill_draw_pile(&mut self, cmp: usize) {
+        if self.draw_pile.size() >= cmp {
+            return;
+        }
 
-        println!("Formatted Message: {}", &fmt_msg);
+        if self.used_pile.size() > 1 {
+            let mut discards = self.used_pile.split_off(1);
+            discards.shuffle(&mut self.rng);
+            self.journal.record(GameEvent::DiscardsReshuffled {
+                round: self.round_n,
+                cards: discards.size(),
+            });
+            self.draw_pile.append(discards.into_iter());
+        }
 
-        fmt_msg
+        // There has to be a card to draw, or the turn couldn't be played.
+        let last_resort = self.config.add_decks || self.draw_pile.size() == 0;
+        let deck = self.new_deck();
+        if last_resort
+            && self
+                .draw_pile
+                .check_size_and_append(cmp, &deck, &mut self.rng)
+        {
+            self.journal.record(GameEvent::DeckAdded {
+                round: self.round_n,
+            });
+            self.cards.add(&deck);
+        }
+    }
+
+    /// Records that a rule's action was applied after `player`'s turn, and tells the players about it. Cards moved by it are only shown to the players they moved between.
+    fn rule_invoked(
+        &mut self,
+        player: usize,
+        result: RuleActionResult,
+        interface: &mut dyn PlayerInterface,
+    ) -> RenderResult<()> {
+        let public = result.seen_by(None);
+        self.journal
+            .record(GameEvent::RuleTriggered { player, result });
+        interface.notify(&self.public_view(), Notice::RuleInvocation(public))
+    }
+
+    /// Fills in the `{card}`, `{value}` and `{suit}` of a `Say` rule's message for `card` (see `rule::fill_message`).
+    ///
+    /// `Action::parse` only makes messages that can be filled in, but if a message can't be (eg. it was loaded from an old save), it is used as it is.
+    pub fn parse_message(message: String, card: Card) -> String {
+        rule::fill_message(&message, card).unwrap_or(message)
     }
 
     /// Given the mistakes, `mistake_screen` renders a mistake screen and other functionality if neccessary.
@@ -560,7 +834,9 @@ impl Game {
         mut mistakes: Option<Mistakes>,
         card_error: bool,
         just_used_card: Option<Card>,
+        interface: &mut dyn PlayerInterface,
     ) -> RenderResult<()> {
+        let mut penalized = false;
         let mut all_incs = vec![]; // All "Incorrect use of _."s
         let mut all_fails = vec![]; // All "Failure to say _."s
         let mut card_error_current = true;
@@ -568,9 +844,6 @@ impl Game {
 
         loop {
             let (incs, fails) = &mistakes.unwrap_or((vec![], vec![]));
-            // println!("BEFORE PRINTING MISTAKES");
-            // self.print_mistakes((incs.clone(), fails.clone()));
-            // println!("AFTER");
 
             let n = incs.len()
                 + fails.len()
@@ -585,150 +858,113 @@ impl Game {
                 break;
             }
 
-            let mut drawn = self.draw_pile.deal(n, &mut self.players[self.player_index]);
+            penalized = true;
+            let mut drawn = self.deal_to(self.player_index, n);
             if card_error {
-                if let Some(card) = just_used_card {
-                    // self.used_pile
-                    self.players[self.player_index].draw(&mut self.used_pile);
-                    drawn.insert(0, card);
+                // The card they just played goes back into their hand.
+                if just_used_card.is_some() {
+                    if let Ok(card) = self.players[self.player_index].draw(&mut self.used_pile) {
+                        drawn.insert(0, card);
+                    }
                 }
                 just_used_card = None;
             }
-
-            all_incs.extend(incs.iter().map(|v| v.clone()));
-            all_fails.extend(fails.iter().map(|v| v.clone()));
-
-            let quotes = self.screen_request_turn_failures(
-                all_incs.clone(),
-                incs.len(),
-                all_fails.clone(),
-                fails.len(),
+            self.debug_check_cards("penalizing a player");
+
+            self.journal.record(GameEvent::Penalty {
+                player: self.player_index,
+                incs: incs.clone(),
+                fails: fails.clone(),
+                card_error: card_error && card_error_current,
+                drawn: drawn.iter().copied().map(Some).collect(),
+            });
+
+            all_incs.extend(incs.iter().cloned());
+            all_fails.extend(fails.iter().cloned());
+
+            let report = MistakeReport {
+                incs: all_incs.clone(),
+                current_incs: incs.len(),
+                fails: all_fails.clone(),
+                current_fails: fails.len(),
                 drawn,
                 card_error,
-                card_error_current,
-            )?;
+                current_card_error: card_error_current,
+            };
+            let quotes =
+                interface.request_penalty_speech(&self.view(self.player_index), &report)?;
             card_error_current = false;
-            mistakes = self.check_quotes(&quotes, &vec![String::from("thank you")])
-        }
-
-        println!("After check");
-
-        Ok(())
-    }
 
-    /// A helpful method for debugging purposes for printing the mistakes.
-    fn print_mistakes(&self, mistakes: Mistakes) {
-        for m in mistakes.0 {
-            print!("Incorrect use of {}. ", m);
+            // What they have to say is filled in with the top card, so there's nothing to check if the used pile is empty.
+            let Some(top) = self.used_pile.top() else {
+                break;
+            };
+            let reqs = self.required_quotes(&[Trigger::Penalty], top);
+            mistakes = self.check_quotes(&quotes, &reqs);
+            if let Some((_, fails)) = &mistakes {
+                self.blame_rules(&[Trigger::Penalty], top, fails, false);
+            }
         }
 
-        print!("\n");
-
-        for m in mistakes.1 {
-            print!("Failure to say {}. ", m);
+        if penalized {
+            let actions = self.triggered_actions(&[Trigger::Penalty]);
+            self.apply_actions(&actions, interface)?;
         }
-
-        print!("\n");
+        Ok(())
     }
 
-    /// Whether a card **about to be played** is a legal play
+    /// Whether a card **about to be played** by the current player is a legal play, by the play policy and the legality rules (see `RuleMap::allows`).
     /// Must be called before the card enters `self.used_pile`.
-    fn validate_card_played(&self, card: Card) -> bool {
-        self.used_pile[0].suit().is_red() == card.suit().is_red()
-            || self.used_pile[0].value() == card.value()
+    pub fn validate_card_played(&self, card: Card) -> bool {
+        let played: Vec<Card> = std::iter::once(card)
+            .chain(self.used_pile.cards().iter().copied())
+            .collect();
+
+        self.rule_map.allows(&Situation {
+            trigger: Trigger::CardPlayed,
+            played: &played,
+            cards_left: self.current_player().num_cards().saturating_sub(1),
+        })
     }
 
-    /// Utility UI function that requests notifies about change of turn.
-    fn screen_next_player(&self) -> RenderResult<()> {
-        PlayScreen::NewTurn.render_then_wait(Some(self))
-    }
-    /// Utility UI function that requests notifies about change of turn.
-    fn screen_win(&self, winner: usize) -> RenderResult<()> {
-        PlayScreen::Win { winner }.render_then_wait(Some(self))
+    /// Returns the indices of the cards in the current player's hand that are legal to play.
+    pub fn legal_plays(&self) -> Vec<usize> {
+        self.current_player()
+            .hand()
+            .iter()
+            .enumerate()
+            .filter(|(_, card)| self.validate_card_played(**card))
+            .map(|(i, _)| i)
+            .collect()
     }
 
-    /// Utility UI function that requests their move action
-    /// for their turn.
-    ///
-    /// Returns `Some(usize)` representing the card they play,
-    /// or `None` if they draw from the deck.
-    fn screen_request_card_play(&self) -> RenderResult<Option<usize>> {
+    /// Asks `interface` for the current player's move, until they make one that is actually possible. Saving the game is done here, and then they are asked again.
+    fn request_move(&self, interface: &mut dyn PlayerInterface) -> RenderResult<Move> {
         loop {
-            let card_id = PlayScreen::Turn(TurnState::Action).render_then_input(Some(self))?;
-            let card_id = card_id.as_str().trim();
-
-            match card_id {
-                "D" | "d" => {
-                    return Ok(None);
-                }
-                _ => {
-                    if let Ok(n) = card_id.parse::<usize>() {
-                        if n >= 1 && n <= self.current_player().num_cards() {
-                            return Ok(Some(n - 1));
-                        }
+            match interface.request_move(&self.view(self.player_index))? {
+                Move::Play(index) if index >= self.current_player().num_cards() => continue,
+                Move::Save(path) => {
+                    if let Err(err) = self.save(&path) {
+                        let issue = err.to_string();
+                        interface
+                            .notify(&self.public_view(), Notice::SaveFailed { path, issue })?;
                     }
                 }
+                play => return Ok(play),
             }
         }
     }
 
-    fn parse_action_quotes(data: String) -> Vec<String> {
-        data.as_str()
-            .split(".")
-            .map(|v| v.to_lowercase().trim().to_string())
-            .filter(|v| !v.is_empty())
-            .collect()
-    }
-
-    /// Utility UI function that requests their speaking action
-    /// for their turn.
-    ///
-    /// Player's response is formatted "answer a. answer b."
-    ///
-    /// Returns `Vec<String>` representing the things they say.
-    fn screen_request_turn_speak(
-        &self,
-        did_draw: bool,
-        action_card: Card,
-    ) -> RenderResult<Vec<String>> {
-        PlayScreen::Turn(TurnState::Speak(did_draw, action_card))
-            .render_then_input(Some(self))
-            .map(Self::parse_action_quotes)
-    }
-    /// Utility UI functin that shows their failures when they make them
-    /// and allows them to say something in response to getting a card.
-    ///
-    /// Same format as `self.screen_request_turn_speak`
-    ///
-    /// Returns `Vec<String>` representing the things they say.
-    fn screen_request_turn_failures(
-        &self,
-        incs: Vec<String>,
-        current_incs: usize,
-        fails: Vec<String>,
-        current_fails: usize,
-        drawn: Vec<Card>,
-        card_error: bool,
-        current_card_error: bool,
-    ) -> RenderResult<Vec<String>> {
-        PlayScreen::Mistake {
-            incs,
-            current_incs,
-            fails,
-            current_fails,
-            drawn,
-            card_error,
-            current_card_error,
-        }
-        .render_then_input(Some(self))
-        .map(Self::parse_action_quotes)
-    }
-
     /// Returns an immutable reference to the current player.
     pub fn current_player(&self) -> &Player {
         &self.players[self.player_index]
     }
 
+    /// Returns the index of the current player.
+    pub fn player_index(&self) -> usize {
+        self.player_index
+    }
+
     /// Returns a mutable reference to the current player.
     pub fn current_player_mut(&mut self) -> &mut Player {
         &mut self.players[self.player_index]
@@ -764,6 +1000,98 @@ impl Game {
         self.round_over
     }
 
+    /// Returns whether a round has been dealt and nobody has won it yet.
+    pub fn round_in_progress(&self) -> bool {
+        self.round_n > 0 && !self.round_over
+    }
+
+    /// Saves the whole state of the game as JSON to the file at `path`, so it can be resumed later with `Game::load`.
+    ///
+    /// This includes all of the rules that have been added, so they can be carried on to another session.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> std::io::Result<()> {
+        serde_json::to_writer(BufWriter::new(File::create(path)?), self)?;
+        Ok(())
+    }
+
+    /// Returns the game as `player` can see it: their own hand, but only how many cards everybody else has.
+    pub fn view(&self, player: usize) -> PlayerView {
+        PlayerView::new(self, Some(player))
+    }
+
+    /// Returns the game as everybody at the table can see it, without anyone's hand.
+    pub fn public_view(&self) -> PlayerView {
+        PlayerView::new(self, None)
+    }
+
+    /// Loads a game that was saved with `Game::save` from the file at `path`. Playing it continues where it was saved.
+    ///
+    /// The cards in the saved game are taken to be the right ones, so they're only checked from here on (see `Game::check_cards`).
+    pub fn load<P: AsRef<Path>>(path: P) -> std::io::Result<Self> {
+        let mut game: Self = serde_json::from_reader(BufReader::new(File::open(path)?))?;
+        game.cards = game.card_counts();
+        Ok(game)
+    }
+
+    /// Returns the number of the current round, counting from 1. It is 0 before the first round.
+    pub fn round_n(&self) -> usize {
+        self.round_n
+    }
+
+    /// Returns the record of everything that has happened in the game so far.
+    pub fn journal(&self) -> &Journal {
+        &self.journal
+    }
+
+    /// Sets where the game's events are sent as they happen, in addition to being kept in the journal.
+    pub fn set_event_sink(&mut self, sink: Box<dyn EventSink>) {
+        self.journal.set_sink(sink);
+    }
+
+    /// Returns the seed all of the game's randomness comes from.
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Replaces all of the game's rules with `rule_map`, eg. to play with the rule set of another game.
+    pub fn set_rule_map(&mut self, rule_map: RuleMap) {
+        self.rule_map = rule_map;
+    }
+
+    /// Sets the basic rule for which cards can be played, eg. to play by suit or value instead of colour or value.
+    pub fn set_play_policy(&mut self, play_policy: PlayPolicy) {
+        self.rule_map.set_play_policy(play_policy);
+    }
+
+    /// Returns the order the rules' actions are applied in.
+    pub fn priority(&self) -> &Priority {
+        &self.priority
+    }
+
+    /// Sets the order the rules' actions are applied in.
+    ///
+    /// Returns `Err(IncompletePriority)`, leaving the priority as it was, unless `priority` has every action exactly once.
+    pub fn set_priority(&mut self, priority: Priority) -> Result<(), IncompletePriority> {
+        if !priority.is_complete() {
+            return Err(IncompletePriority);
+        }
+
+        self.priority = priority;
+        Ok(())
+    }
+
+    /// Returns the settings the game was set up with.
+    pub fn config(&self) -> &GameConfig {
+        &self.config
+    }
+
+    /// Puts the rules' actions in a random order, which comes from the game's seed.
+    ///
+    /// The order comes from its own generator, so the game's cards are dealt the same as if it hadn't been shuffled, and a replay (which only has the priority) plays out the same.
+    pub fn shuffle_priority(&mut self) {
+        let mut rng = ChaCha12Rng::seed_from_u64(self.seed ^ PRIORITY_SEED);
+        self.priority = Priority::shuffled(&mut rng);
+    }
+
     /// Returns the map of rules. (For debugging purposes).
     pub fn rule_map(&self) -> &RuleMap {
         &self.rule_map
@@ -781,10 +1109,36 @@ impl Game {
 
         count
     }
+
+    /// Returns how many of each card there are in the game, in the draw pile, the used pile and everybody's hands.
+    pub fn card_counts(&self) -> CardCount {
+        let mut counts = CardCount::of(&self.draw_pile);
+        counts.add(&self.used_pile);
+        for player in &self.players {
+            counts.add(player.hand());
+        }
+        counts
+    }
+
+    /// Checks that the game has exactly the cards it should, after `transition`: cards only move around, except when a round starts or a deck is added to the draw pile.
+    ///
+    /// Returns `Err` with the cards `transition` made or lost if it doesn't.
+    pub fn check_cards(&self, transition: &str) -> Result<(), CardMismatch> {
+        self.cards.compare(&self.card_counts(), transition)
+    }
+
+    /// Checks the cards after `transition` (see `Game::check_cards`), and panics if any were made or lost. Only in debug builds, since counting every card after every change isn't free.
+    fn debug_check_cards(&self, transition: impl std::fmt::Display) {
+        if cfg!(debug_assertions) {
+            if let Err(mismatch) = self.check_cards(&transition.to_string()) {
+                panic!("{}", mismatch);
+            }
+        }
+    }
 }
 
 /// An enum representing the possible orders of play.
-#[derive(Debug)]
+#[derive(Debug, Copy, Clone, Serialize, Deserialize)]
 pub enum Order {
     Forward,
     Backward,
@@ -808,17 +1162,12 @@ impl Order {
     }
 }
 
-#[derive(Debug, Serialize)]
-struct Context {
-    card: String,
-    value: String,
-    suit: String,
-}
-
 #[derive(Debug)]
 pub enum AddingRuleError {
     InvalidAction,
     ConflictingAction,
+    /// There's no rule like the one to remove or modify.
+    NoSuchRule,
 }
 
 pub type AddingRuleResult<T> = Result<T, AddingRuleError>;
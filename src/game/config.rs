@@ -0,0 +1,140 @@
+/*
+ * A "GameConfig" is how a game is set up: how many cards
+ * everybody is dealt, which cards are in a deck (and any
+ * jokers), how many decks make up the draw pile (and
+ * whether more can be added when it runs low),
+ * who goes first each round, and which rules the game starts
+ * with. It's built up with the "with_" methods, eg.
+ * `GameConfig::default().with_hand_size(5).with_decks(2)`,
+ * and given to "Game::with_config".
+ */
+
+use crate::deck::DeckKind;
+use crate::rule::legality::PlayPolicy;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// The settings a game is set up with. The default is the usual game of Mao.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameConfig {
+    /// The number of cards each player is dealt at the start of a round.
+    pub hand_size: usize,
+    /// Which cards are in each deck.
+    pub deck: DeckKind,
+    /// The number of jokers added to each deck.
+    pub jokers: usize,
+    /// The number of shuffled decks the draw pile starts each round with, like a shoe in a casino.
+    pub decks: usize,
+    /// The draw pile is refilled once it has fewer cards than this after dealing.
+    pub refill_below: usize,
+    /// Whether a new deck is added to the draw pile when it runs low, even after the used pile is shuffled back into it. If not, a deck is still added when there isn't a single card left to draw, since a turn can't be played without one.
+    pub add_decks: bool,
+    /// Who goes first each round.
+    pub starting_player: StartingPlayer,
+    /// Whether the game starts with the default rules (see `rule::dsl::DEFAULT_RULES`), or with no rules at all.
+    pub default_rules: bool,
+    /// Which cards can be played, before any legality rules.
+    pub play_policy: PlayPolicy,
+}
+
+/// Who goes first in a round.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum StartingPlayer {
+    /// The player in the first seat always goes first.
+    #[default]
+    First,
+    /// A random player goes first.
+    Random,
+    /// Each round, the next seat along goes first.
+    Rotate,
+    /// The winner of the last round goes first, and the first seat in the first round.
+    LastWinner,
+}
+
+impl Default for GameConfig {
+    fn default() -> Self {
+        Self {
+            hand_size: 7,
+            deck: DeckKind::Standard,
+            jokers: 0,
+            decks: 1,
+            refill_below: 25,
+            add_decks: true,
+            starting_player: StartingPlayer::First,
+            default_rules: true,
+            play_policy: PlayPolicy::default(),
+        }
+    }
+}
+
+impl GameConfig {
+    /// Sets the number of cards each player is dealt, which has to be at least 1.
+    pub fn with_hand_size(mut self, hand_size: usize) -> Self {
+        self.hand_size = hand_size.max(1);
+        self
+    }
+
+    /// Sets which cards are in each deck.
+    pub fn with_deck(mut self, deck: DeckKind) -> Self {
+        self.deck = deck;
+        self
+    }
+
+    /// Sets the number of jokers added to each deck.
+    pub fn with_jokers(mut self, jokers: usize) -> Self {
+        self.jokers = jokers;
+        self
+    }
+
+    /// Sets the number of decks the draw pile starts each round with, which has to be at least 1.
+    pub fn with_decks(mut self, decks: usize) -> Self {
+        self.decks = decks.max(1);
+        self
+    }
+
+    /// Sets how few cards the draw pile can have after dealing before it is refilled.
+    pub fn with_refill_below(mut self, refill_below: usize) -> Self {
+        self.refill_below = refill_below;
+        self
+    }
+
+    /// Sets whether new decks are added to the draw pile when it runs low, as a last resort.
+    pub fn with_add_decks(mut self, add_decks: bool) -> Self {
+        self.add_decks = add_decks;
+        self
+    }
+
+    /// Sets who goes first each round.
+    pub fn with_starting_player(mut self, starting_player: StartingPlayer) -> Self {
+        self.starting_player = starting_player;
+        self
+    }
+
+    /// Sets whether the game starts with the default rules.
+    pub fn with_default_rules(mut self, default_rules: bool) -> Self {
+        self.default_rules = default_rules;
+        self
+    }
+
+    /// Sets which cards can be played, before any legality rules.
+    pub fn with_play_policy(mut self, play_policy: PlayPolicy) -> Self {
+        self.play_policy = play_policy;
+        self
+    }
+}
+
+impl FromStr for StartingPlayer {
+    type Err = ();
+
+    /// Parses who goes first from the command line format: `first`, `random`, `rotate` or `winner`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "first" => Ok(Self::First),
+            "random" => Ok(Self::Random),
+            "rotate" => Ok(Self::Rotate),
+            "winner" | "last winner" => Ok(Self::LastWinner),
+            _ => Err(()),
+        }
+    }
+}
@@ -0,0 +1,100 @@
+/*
+ * Cards can only ever move around in a game of Mao: between
+ * the draw pile, the used pile and the players' hands. The
+ * only way cards are made is by a round starting, or a new
+ * deck being added to the draw pile. A "CardCount" is how
+ * many of each card there are, so the game can check that
+ * every change to it kept the same cards, and find out which
+ * change made or lost some if it didn't.
+ */
+
+use crate::card::Card;
+use enum_iterator::all;
+use std::collections::HashMap;
+use std::fmt::{self, Display, Formatter};
+
+/// How many of each card there are, in any order.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CardCount {
+    counts: HashMap<Card, usize>,
+}
+
+/// The cards that were made (`created`) or went missing (`lost`) in a change to the game (`transition`), which can't happen in a real game.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CardMismatch {
+    pub transition: String,
+    pub created: Vec<Card>,
+    pub lost: Vec<Card>,
+}
+
+impl CardCount {
+    /// Counts each of `cards`.
+    pub fn of<'a>(cards: impl IntoIterator<Item = &'a Card>) -> Self {
+        let mut count = Self::default();
+        count.add(cards);
+        count
+    }
+
+    /// Counts each of `cards` as well.
+    pub fn add<'a>(&mut self, cards: impl IntoIterator<Item = &'a Card>) {
+        for card in cards {
+            *self.counts.entry(*card).or_default() += 1;
+        }
+    }
+
+    /// Returns how many of `card` there are.
+    pub fn count(&self, card: Card) -> usize {
+        self.counts.get(&card).copied().unwrap_or(0)
+    }
+
+    /// Returns how many cards there are altogether.
+    pub fn total(&self) -> usize {
+        self.counts.values().sum()
+    }
+
+    /// Compares the cards there are (`actual`) after `transition` with the cards there should be (`self`).
+    ///
+    /// Returns `Err` with the cards that were made or lost, in deck order, if they aren't the same.
+    pub fn compare(&self, actual: &CardCount, transition: &str) -> Result<(), CardMismatch> {
+        let mut created = vec![];
+        let mut lost = vec![];
+        for card in all::<Card>() {
+            let (expected, actual) = (self.count(card), actual.count(card));
+            created.extend(std::iter::repeat_n(card, actual.saturating_sub(expected)));
+            lost.extend(std::iter::repeat_n(card, expected.saturating_sub(actual)));
+        }
+
+        if created.is_empty() && lost.is_empty() {
+            Ok(())
+        } else {
+            Err(CardMismatch {
+                transition: transition.to_string(),
+                created,
+                lost,
+            })
+        }
+    }
+}
+
+impl Display for CardMismatch {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let list = |cards: &[Card]| {
+            if cards.is_empty() {
+                String::from("none")
+            } else {
+                cards
+                    .iter()
+                    .map(Card::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            }
+        };
+        write!(
+            f,
+            "cards changed while {}: created {}; lost {}",
+            self.transition,
+            list(&self.created),
+            list(&self.lost)
+        )
+    }
+}
@@ -0,0 +1,123 @@
+/*
+ * This file has the "PlayerInterface" trait, which is how the
+ * game asks for the decisions it needs (which card to play,
+ * what to say, what rule to add) and tells the players what
+ * happened. The terminal screens are one implementation, but
+ * anything else (a bot, a test script, ...) can be plugged in
+ * instead without touching the game logic.
+ */
+
+use crate::card::Card;
+use crate::render::engine::RenderResult;
+use crate::render::play_screen::RuleActionResult;
+use crate::rule::priority::Priority;
+use crate::rule::{Rule, RuleChange};
+use crate::view::PlayerView;
+use serde::{Deserialize, Serialize};
+
+pub mod bot;
+pub mod recording;
+pub mod replay;
+pub mod scripted;
+pub mod table;
+pub mod terminal;
+
+/// A trait representing whatever is sitting at the table, making the decisions for the players.
+///
+/// Every method is given a `PlayerView` of the game as it currently is, so the implementor can look at the current player, the top of the used pile, etc. Requests get the view of the player being asked, with their hand, and notices get the view everybody has.
+pub trait PlayerInterface {
+    /// Tells the players about something that happened, without expecting a decision back.
+    fn notify(&mut self, view: &PlayerView, notice: Notice) -> RenderResult<()>;
+
+    /// Asks the current player whether they want to play a card or draw one.
+    fn request_move(&mut self, view: &PlayerView) -> RenderResult<Move>;
+
+    /// Asks the current player what they say after playing or drawing `action_card`.
+    fn request_speech(
+        &mut self,
+        view: &PlayerView,
+        did_draw: bool,
+        action_card: Card,
+    ) -> RenderResult<Vec<String>>;
+
+    /// Shows the current player the mistakes they made, and asks what they say in response to getting their penalty cards.
+    fn request_penalty_speech(
+        &mut self,
+        view: &PlayerView,
+        report: &MistakeReport,
+    ) -> RenderResult<Vec<String>>;
+
+    /// Asks the players whether they want to play another round.
+    fn confirm_next_round(&mut self, view: &PlayerView) -> RenderResult<bool>;
+
+    /// Asks `winner` for a change to the rules: a new rule to add to the game, or a rule to remove or modify.
+    fn request_rule(&mut self, view: &PlayerView, winner: usize) -> RenderResult<RuleChange>;
+
+    /// Asks `winner` for a new order for the rules' actions to be applied in (starting from `view.priority()`), or `None` to keep it.
+    fn request_priority(
+        &mut self,
+        view: &PlayerView,
+        winner: usize,
+    ) -> RenderResult<Option<Priority>>;
+}
+
+/// A move made by a player at the start of their turn.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Move {
+    /// Play the card at this index of the player's hand.
+    Play(usize),
+    /// Draw a card from the draw pile.
+    Draw,
+    /// Save the game to the file at this path. This doesn't use up the turn, the player is asked for their move again afterwards.
+    Save(String),
+}
+
+/// Things that happen in the game which the players should be told about.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum Notice {
+    /// A new round is starting.
+    NewRound { round_n: usize },
+    /// It's the next player's turn.
+    NewTurn,
+    /// A rule's action was automatically applied.
+    RuleInvocation(RuleActionResult),
+    /// The rule `winner` tried to add conflicts with an existing one.
+    RuleExists { winner: usize },
+    /// There's no rule like the one `winner` tried to remove or modify.
+    NoSuchRule { winner: usize },
+    /// Somebody got rid of all their cards. `art` is a random number picking the picture shown, so that it's the same for the same seed.
+    Win { winner: usize, art: usize },
+    /// The game couldn't be saved to `path`.
+    SaveFailed { path: String, issue: String },
+    /// The players stopped playing, so every rule is shown, with who made it and how many penalties it caused.
+    RulesRevealed(Vec<Rule>),
+}
+
+/// Everything a player is told when they are penalized for a mistake.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MistakeReport {
+    /// Things that should not have been said.
+    pub incs: Vec<String>,
+    /// ... in this round
+    pub current_incs: usize,
+    /// Things that should have been said.
+    pub fails: Vec<String>,
+    /// ... in this round
+    pub current_fails: usize,
+    /// Cards drawn as punishment.
+    pub drawn: Vec<Card>,
+    /// If you were given back your own card due to error.
+    pub card_error: bool,
+    /// ... in this round
+    pub current_card_error: bool,
+}
+
+/// Splits what a player said into the separate things they said.
+///
+/// A player's response is formatted "answer a. answer b.", and is case insensitive.
+pub fn parse_quotes(data: &str) -> Vec<String> {
+    data.split('.')
+        .map(|v| v.to_lowercase().trim().to_string())
+        .filter(|v| !v.is_empty())
+        .collect()
+}
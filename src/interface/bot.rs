@@ -0,0 +1,385 @@
+/*
+ * Computer controlled players. Each kind of bot is its own
+ * "PlayerInterface", making every decision for one seat at
+ * the table (see "TableInterface" for sharing a game between
+ * bots and people).
+ *
+ * The easy bot plays a random legal card and only knows the
+ * rules everybody is told about. The hard bot starts out
+ * knowing nothing, and learns what to say from the mistakes
+ * it gets penalized for. The expert bot watches everything
+ * that happens to everyone, and works out the rules with a
+ * "RuleModel".
+ */
+
+use super::{MistakeReport, Move, Notice, PlayerInterface};
+use crate::card::{Card, Suit, Value};
+use crate::render::engine::RenderResult;
+use crate::rule::inference::RuleModel;
+use crate::rule::priority::Priority;
+use crate::rule::rule_map::RuleMap;
+use crate::rule::{Action, Event, Rule, RuleChange};
+use crate::view::PlayerView;
+use enum_iterator::all;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha12Rng;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+
+/// The kinds of bots that can sit at the table.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BotKind {
+    /// A `RandomBot`.
+    Easy,
+    /// A `LearningBot`.
+    Hard,
+    /// An `InferenceBot`.
+    Expert,
+}
+
+impl BotKind {
+    /// Creates a new bot of this kind, making its random choices with `seed`.
+    pub fn interface(&self, seed: u64) -> Box<dyn PlayerInterface> {
+        match self {
+            Self::Easy => Box::new(RandomBot::new(seed)),
+            Self::Hard => Box::new(LearningBot::new(seed)),
+            Self::Expert => Box::new(InferenceBot::new(seed)),
+        }
+    }
+
+    /// Returns the lowercase name of the bot kind, as used in `bot:<name>`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Easy => "easy",
+            Self::Hard => "hard",
+            Self::Expert => "expert",
+        }
+    }
+}
+
+impl FromStr for BotKind {
+    type Err = ();
+
+    /// Parses a bot from the name select format, `bot:easy`, `bot:hard` or `bot:expert`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().strip_prefix("bot:") {
+            Some("easy") => Ok(Self::Easy),
+            Some("hard") => Ok(Self::Hard),
+            Some("expert") => Ok(Self::Expert),
+            _ => Err(()),
+        }
+    }
+}
+
+/// A bot that plays a random legal card if it has one (and otherwise draws), and only says what everybody is told to: "thank you" and "mao".
+#[derive(Debug)]
+pub struct RandomBot {
+    rng: ChaCha12Rng,
+}
+
+impl RandomBot {
+    /// Creates a new `RandomBot`, making its random choices with `seed`.
+    pub fn new(seed: u64) -> Self {
+        Self {
+            rng: ChaCha12Rng::seed_from_u64(seed),
+        }
+    }
+}
+
+impl PlayerInterface for RandomBot {
+    fn notify(&mut self, _view: &PlayerView, _notice: Notice) -> RenderResult<()> {
+        Ok(())
+    }
+
+    fn request_move(&mut self, view: &PlayerView) -> RenderResult<Move> {
+        Ok(random_legal_move(view, &mut self.rng))
+    }
+
+    fn request_speech(
+        &mut self,
+        view: &PlayerView,
+        did_draw: bool,
+        _action_card: Card,
+    ) -> RenderResult<Vec<String>> {
+        Ok(if did_draw {
+            vec!["thank you".to_string()]
+        } else if view.hand().is_empty() {
+            vec!["mao".to_string()]
+        } else {
+            vec![]
+        })
+    }
+
+    fn request_penalty_speech(
+        &mut self,
+        _view: &PlayerView,
+        _report: &MistakeReport,
+    ) -> RenderResult<Vec<String>> {
+        Ok(vec!["thank you".to_string()])
+    }
+
+    fn confirm_next_round(&mut self, _view: &PlayerView) -> RenderResult<bool> {
+        Ok(true)
+    }
+
+    fn request_rule(&mut self, _view: &PlayerView, _winner: usize) -> RenderResult<RuleChange> {
+        Ok(RuleChange::Add(random_rule(&mut self.rng)))
+    }
+
+    fn request_priority(
+        &mut self,
+        _view: &PlayerView,
+        _winner: usize,
+    ) -> RenderResult<Option<Priority>> {
+        Ok(None)
+    }
+}
+
+/// Something about what a player just did, which a rule might be triggered by.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+enum Trigger {
+    Drew,
+    Penalized,
+    LastCard,
+    Card(Card),
+    Value(Value),
+    Suit(Suit),
+}
+
+/// A bot that plays a random legal card, and learns what it has to say from its penalties.
+///
+/// Every time it fails to say something, it guesses that whatever it just did (the card, its value, its suit, ...) is why it had to be said, and says it whenever that happens again. Every time it incorrectly says something, the guesses for what it just did are dropped.
+#[derive(Debug)]
+pub struct LearningBot {
+    rng: ChaCha12Rng,
+    /// Every phrase learned, and what is thought to trigger it.
+    learned: HashMap<String, HashSet<Trigger>>,
+    /// What the bot did that it's about to be judged on.
+    context: Vec<Trigger>,
+}
+
+impl LearningBot {
+    /// Creates a new `LearningBot`, making its random choices with `seed`.
+    pub fn new(seed: u64) -> Self {
+        Self {
+            rng: ChaCha12Rng::seed_from_u64(seed),
+            learned: HashMap::new(),
+            context: vec![],
+        }
+    }
+
+    /// Returns everything the bot has learned to say, sorted.
+    pub fn phrases(&self) -> Vec<&String> {
+        let mut phrases: Vec<_> = self
+            .learned
+            .iter()
+            .filter(|(_, triggers)| !triggers.is_empty())
+            .map(|(phrase, _)| phrase)
+            .collect();
+        phrases.sort();
+        phrases
+    }
+
+    /// Changes what the bot thinks it's being judged on, and returns everything it has learned to say for it.
+    fn speak(&mut self, context: Vec<Trigger>) -> Vec<String> {
+        self.context = context;
+
+        let mut phrases: Vec<_> = self
+            .learned
+            .iter()
+            .filter(|(_, triggers)| self.context.iter().any(|t| triggers.contains(t)))
+            .map(|(phrase, _)| phrase.clone())
+            .collect();
+        phrases.sort();
+        phrases
+    }
+
+    /// Updates what the bot has learned, from the mistakes it was just penalized for.
+    fn learn(&mut self, report: &MistakeReport) {
+        for fail in report.fails.iter().rev().take(report.current_fails) {
+            self.learned
+                .entry(fail.clone())
+                .or_default()
+                .extend(self.context.iter().copied());
+        }
+
+        for inc in report.incs.iter().rev().take(report.current_incs) {
+            if let Some(triggers) = self.learned.get_mut(inc) {
+                triggers.retain(|t| !self.context.contains(t));
+            }
+        }
+    }
+}
+
+impl PlayerInterface for LearningBot {
+    fn notify(&mut self, _view: &PlayerView, _notice: Notice) -> RenderResult<()> {
+        Ok(())
+    }
+
+    fn request_move(&mut self, view: &PlayerView) -> RenderResult<Move> {
+        Ok(random_legal_move(view, &mut self.rng))
+    }
+
+    fn request_speech(
+        &mut self,
+        view: &PlayerView,
+        did_draw: bool,
+        action_card: Card,
+    ) -> RenderResult<Vec<String>> {
+        let context = if did_draw {
+            vec![Trigger::Drew]
+        } else {
+            let mut context = vec![
+                Trigger::Card(action_card),
+                Trigger::Value(action_card.value()),
+                Trigger::Suit(action_card.suit()),
+            ];
+            if view.hand().is_empty() {
+                context.push(Trigger::LastCard);
+            }
+            context
+        };
+
+        Ok(self.speak(context))
+    }
+
+    fn request_penalty_speech(
+        &mut self,
+        _view: &PlayerView,
+        report: &MistakeReport,
+    ) -> RenderResult<Vec<String>> {
+        self.learn(report);
+        Ok(self.speak(vec![Trigger::Penalized]))
+    }
+
+    fn confirm_next_round(&mut self, _view: &PlayerView) -> RenderResult<bool> {
+        Ok(true)
+    }
+
+    fn request_rule(&mut self, _view: &PlayerView, _winner: usize) -> RenderResult<RuleChange> {
+        Ok(RuleChange::Add(random_rule(&mut self.rng)))
+    }
+
+    fn request_priority(
+        &mut self,
+        _view: &PlayerView,
+        _winner: usize,
+    ) -> RenderResult<Option<Priority>> {
+        Ok(None)
+    }
+}
+
+/// A bot that plays a random legal card, and infers the rules from everything that happens in the game: rules being invoked and everybody's penalties.
+#[derive(Debug)]
+pub struct InferenceBot {
+    rng: ChaCha12Rng,
+    model: RuleModel,
+}
+
+impl InferenceBot {
+    /// Creates a new `InferenceBot`, making its random choices with `seed`.
+    pub fn new(seed: u64) -> Self {
+        Self {
+            rng: ChaCha12Rng::seed_from_u64(seed),
+            model: RuleModel::new(),
+        }
+    }
+
+    /// Returns the rules the bot currently believes are in the game.
+    pub fn beliefs(&self) -> RuleMap {
+        self.model.beliefs()
+    }
+
+    /// Observes the events in `view` the bot hasn't seen yet.
+    fn observe(&mut self, view: &PlayerView) {
+        self.model
+            .observe_events(view.events_start(), view.events());
+    }
+}
+
+impl PlayerInterface for InferenceBot {
+    fn notify(&mut self, view: &PlayerView, _notice: Notice) -> RenderResult<()> {
+        self.observe(view);
+        Ok(())
+    }
+
+    fn request_move(&mut self, view: &PlayerView) -> RenderResult<Move> {
+        self.observe(view);
+        Ok(random_legal_move(view, &mut self.rng))
+    }
+
+    fn request_speech(
+        &mut self,
+        view: &PlayerView,
+        did_draw: bool,
+        action_card: Card,
+    ) -> RenderResult<Vec<String>> {
+        self.observe(view);
+
+        if did_draw {
+            return Ok(vec!["thank you".to_string()]);
+        }
+
+        let mut quotes = self.model.phrases_for(action_card);
+        if view.hand().is_empty() {
+            quotes.push("mao".to_string());
+        }
+        self.model.said(&quotes);
+        Ok(quotes)
+    }
+
+    fn request_penalty_speech(
+        &mut self,
+        view: &PlayerView,
+        _report: &MistakeReport,
+    ) -> RenderResult<Vec<String>> {
+        self.observe(view);
+        Ok(vec!["thank you".to_string()])
+    }
+
+    fn confirm_next_round(&mut self, _view: &PlayerView) -> RenderResult<bool> {
+        Ok(true)
+    }
+
+    fn request_rule(&mut self, view: &PlayerView, _winner: usize) -> RenderResult<RuleChange> {
+        self.observe(view);
+        Ok(RuleChange::Add(random_rule(&mut self.rng)))
+    }
+
+    fn request_priority(
+        &mut self,
+        _view: &PlayerView,
+        _winner: usize,
+    ) -> RenderResult<Option<Priority>> {
+        Ok(None)
+    }
+}
+
+/// Picks a random legal card from the current player's hand to play, or draws if there isn't one.
+fn random_legal_move<R: Rng + ?Sized>(view: &PlayerView, rng: &mut R) -> Move {
+    view.legal_plays()
+        .choose(rng)
+        .map_or(Move::Draw, |&i| Move::Play(i))
+}
+
+/// Makes up a random rule, on a value or a suit. Jokers are left out, since most games are played without them.
+fn random_rule<R: Rng + ?Sized>(rng: &mut R) -> Rule {
+    let event = if rng.gen_bool(0.5) {
+        let values: Vec<_> = all::<Value>().filter(|&v| v != Value::Joker).collect();
+        Event::ValuePlayed(*values.choose(rng).unwrap())
+    } else {
+        Event::SuitPlayed(*all::<Suit>().collect::<Vec<_>>().choose(rng).unwrap())
+    };
+
+    let action = match rng.gen_range(0..5) {
+        0 => Action::Draw(1),
+        1 => Action::Repeat,
+        2 => Action::Reverse,
+        3 => Action::Skip(1),
+        _ => Action::Say("{value} {suit}".to_string()),
+    };
+
+    Rule::new(event, action)
+}
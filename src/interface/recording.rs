@@ -0,0 +1,97 @@
+/*
+ * The "RecordingInterface" sits between the game and another
+ * interface, passing everything through while keeping a copy
+ * of every decision made, so the game can be saved as a replay.
+ */
+
+use super::{MistakeReport, Move, Notice, PlayerInterface};
+use crate::card::Card;
+use crate::render::engine::RenderResult;
+use crate::replay::Input;
+use crate::rule::priority::Priority;
+use crate::rule::RuleChange;
+use crate::view::PlayerView;
+
+/// A `PlayerInterface` that records every decision made by the interface it wraps.
+pub struct RecordingInterface<'a> {
+    inner: &'a mut dyn PlayerInterface,
+    inputs: Vec<Input>,
+}
+
+impl<'a> RecordingInterface<'a> {
+    /// Creates a new `RecordingInterface` which gets its decisions from `inner`.
+    pub fn new(inner: &'a mut dyn PlayerInterface) -> Self {
+        Self {
+            inner,
+            inputs: vec![],
+        }
+    }
+
+    /// Returns every decision made so far, in order.
+    pub fn inputs(&self) -> &[Input] {
+        &self.inputs
+    }
+
+    /// Stops recording, and returns every decision that was made.
+    pub fn into_inputs(self) -> Vec<Input> {
+        self.inputs
+    }
+}
+
+impl<'a> PlayerInterface for RecordingInterface<'a> {
+    fn notify(&mut self, view: &PlayerView, notice: Notice) -> RenderResult<()> {
+        self.inner.notify(view, notice)
+    }
+
+    fn request_move(&mut self, view: &PlayerView) -> RenderResult<Move> {
+        let mov = self.inner.request_move(view)?;
+        // Saving doesn't change the game, so there's nothing to replay.
+        if !matches!(mov, Move::Save(_)) {
+            self.inputs.push(Input::Move(mov.clone()));
+        }
+        Ok(mov)
+    }
+
+    fn request_speech(
+        &mut self,
+        view: &PlayerView,
+        did_draw: bool,
+        action_card: Card,
+    ) -> RenderResult<Vec<String>> {
+        let quotes = self.inner.request_speech(view, did_draw, action_card)?;
+        self.inputs.push(Input::Speech(quotes.clone()));
+        Ok(quotes)
+    }
+
+    fn request_penalty_speech(
+        &mut self,
+        view: &PlayerView,
+        report: &MistakeReport,
+    ) -> RenderResult<Vec<String>> {
+        let quotes = self.inner.request_penalty_speech(view, report)?;
+        self.inputs.push(Input::Speech(quotes.clone()));
+        Ok(quotes)
+    }
+
+    fn confirm_next_round(&mut self, view: &PlayerView) -> RenderResult<bool> {
+        let again = self.inner.confirm_next_round(view)?;
+        self.inputs.push(Input::NextRound(again));
+        Ok(again)
+    }
+
+    fn request_rule(&mut self, view: &PlayerView, winner: usize) -> RenderResult<RuleChange> {
+        let change = self.inner.request_rule(view, winner)?;
+        self.inputs.push(Input::RuleChange(change.clone()));
+        Ok(change)
+    }
+
+    fn request_priority(
+        &mut self,
+        view: &PlayerView,
+        winner: usize,
+    ) -> RenderResult<Option<Priority>> {
+        let priority = self.inner.request_priority(view, winner)?;
+        self.inputs.push(Input::Priority(priority.clone()));
+        Ok(priority)
+    }
+}
@@ -0,0 +1,198 @@
+/*
+ * The "ReplayInterface" plays a game back from the inputs of a
+ * "Replay". It can also keep a rendered frame of every screen
+ * that would have been shown along the way, for the replay
+ * viewer to step through afterwards.
+ */
+
+use super::{MistakeReport, Move, Notice, PlayerInterface};
+use crate::card::Card;
+use crate::render::engine::{RenderError, RenderResult, Screen, TextFrameBuffer};
+use crate::render::play_more_confirm_screen::PlayMoreConfirmScreen;
+use crate::render::play_screen::{CreateRuleState, PlayScreen, TurnState};
+use crate::replay::Input;
+use crate::rule::priority::Priority;
+use crate::rule::RuleChange;
+use crate::view::PlayerView;
+use std::collections::VecDeque;
+
+/// A `PlayerInterface` that gives the game the inputs of a replay, in order.
+pub struct ReplayInterface {
+    inputs: VecDeque<Input>,
+    given: usize, // How many inputs have been given so far.
+    frames: Option<Vec<ReplayFrame>>,
+}
+
+/// One step of a replay: a screen as it was shown, and the input that was given to it, if any.
+#[derive(Clone)]
+pub struct ReplayFrame {
+    pub frame: TextFrameBuffer,
+    pub input: Option<Input>,
+}
+
+impl ReplayInterface {
+    /// Creates a new `ReplayInterface` giving the game `inputs`.
+    pub fn new(inputs: Vec<Input>) -> Self {
+        Self {
+            inputs: inputs.into(),
+            given: 0,
+            frames: None,
+        }
+    }
+
+    /// Makes the interface render and keep every screen along the way. This needs a terminal to get the dimensions from.
+    pub fn capture_frames(mut self) -> Self {
+        self.frames = Some(vec![]);
+        self
+    }
+
+    /// Returns every frame captured so far.
+    pub fn frames(&self) -> &[ReplayFrame] {
+        self.frames.as_deref().unwrap_or_default()
+    }
+
+    /// Stops the replay, and returns every frame that was captured.
+    pub fn into_frames(self) -> Vec<ReplayFrame> {
+        self.frames.unwrap_or_default()
+    }
+
+    /// Returns the number of inputs left in the replay.
+    pub fn remaining(&self) -> usize {
+        self.inputs.len()
+    }
+
+    /// Takes the next input from the replay, or returns `Err` if there is none left.
+    fn next_input(&mut self) -> RenderResult<Input> {
+        let input = self.inputs.pop_front().ok_or(RenderError::ScriptEnded)?;
+        self.given += 1;
+        Ok(input)
+    }
+
+    /// Keeps `screen` as the next frame, if frames are being captured.
+    fn capture(
+        &mut self,
+        screen: &dyn Screen,
+        view: &PlayerView,
+        input: Option<&Input>,
+    ) -> RenderResult<()> {
+        if let Some(frames) = &mut self.frames {
+            frames.push(ReplayFrame {
+                frame: screen.render_to_frame(Some(view))?,
+                input: input.cloned(),
+            });
+        }
+        Ok(())
+    }
+}
+
+impl std::fmt::Debug for ReplayFrame {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ReplayFrame")
+            .field("input", &self.input)
+            .finish_non_exhaustive()
+    }
+}
+
+impl PlayerInterface for ReplayInterface {
+    fn notify(&mut self, view: &PlayerView, notice: Notice) -> RenderResult<()> {
+        self.capture(&PlayScreen::from(notice), view, None)
+    }
+
+    fn request_move(&mut self, view: &PlayerView) -> RenderResult<Move> {
+        let input = self.next_input()?;
+        self.capture(&PlayScreen::Turn(TurnState::Action), view, Some(&input))?;
+
+        match input {
+            Input::Move(mov) => Ok(mov),
+            _ => Err(RenderError::ReplayMismatch(self.given)),
+        }
+    }
+
+    fn request_speech(
+        &mut self,
+        view: &PlayerView,
+        did_draw: bool,
+        action_card: Card,
+    ) -> RenderResult<Vec<String>> {
+        let input = self.next_input()?;
+        self.capture(
+            &PlayScreen::Turn(TurnState::Speak(did_draw, action_card)),
+            view,
+            Some(&input),
+        )?;
+
+        match input {
+            Input::Speech(quotes) => Ok(quotes),
+            _ => Err(RenderError::ReplayMismatch(self.given)),
+        }
+    }
+
+    fn request_penalty_speech(
+        &mut self,
+        view: &PlayerView,
+        report: &MistakeReport,
+    ) -> RenderResult<Vec<String>> {
+        let input = self.next_input()?;
+        self.capture(&PlayScreen::Mistake(report.clone()), view, Some(&input))?;
+
+        match input {
+            Input::Speech(quotes) => Ok(quotes),
+            _ => Err(RenderError::ReplayMismatch(self.given)),
+        }
+    }
+
+    fn confirm_next_round(&mut self, view: &PlayerView) -> RenderResult<bool> {
+        let input = self.next_input()?;
+        self.capture(&PlayMoreConfirmScreen, view, Some(&input))?;
+
+        match input {
+            Input::NextRound(again) => Ok(again),
+            _ => Err(RenderError::ReplayMismatch(self.given)),
+        }
+    }
+
+    fn request_rule(&mut self, view: &PlayerView, winner: usize) -> RenderResult<RuleChange> {
+        let input = self.next_input()?;
+        self.capture(
+            &PlayScreen::CreateRule {
+                winner,
+                state: CreateRuleState::ChooseChange,
+                format_issue: None,
+            },
+            view,
+            Some(&input),
+        )?;
+
+        match input {
+            Input::RuleChange(change) => Ok(change),
+            _ => Err(RenderError::ReplayMismatch(self.given)),
+        }
+    }
+
+    fn request_priority(
+        &mut self,
+        view: &PlayerView,
+        winner: usize,
+    ) -> RenderResult<Option<Priority>> {
+        // Replays recorded before the priority could be reordered go straight on after the rule.
+        if !matches!(self.inputs.front(), Some(Input::Priority(_))) {
+            return Ok(None);
+        }
+
+        let input = self.next_input()?;
+        self.capture(
+            &PlayScreen::CreateRule {
+                winner,
+                state: CreateRuleState::Reorder,
+                format_issue: None,
+            },
+            view,
+            Some(&input),
+        )?;
+
+        match input {
+            Input::Priority(priority) => Ok(priority),
+            _ => Err(RenderError::ReplayMismatch(self.given)),
+        }
+    }
+}
@@ -0,0 +1,171 @@
+/*
+ * The "ScriptedInterface" plays a game from a script of inputs
+ * instead of the keyboard, with nothing rendered to the screen.
+ * Each line of the script is exactly what would have been typed
+ * into the terminal for the next decision, which makes whole
+ * games reproducible, eg. for tests.
+ */
+
+use super::{parse_quotes, MistakeReport, Move, Notice, PlayerInterface};
+use crate::card::Card;
+use crate::render::engine::{RenderError, RenderResult};
+use crate::rule::priority::Priority;
+use crate::rule::{Action, Event, Rule, RuleChange};
+use crate::view::PlayerView;
+use std::collections::VecDeque;
+use std::path::Path;
+
+/// A `PlayerInterface` that takes every decision from a script and renders nothing.
+///
+/// The script has one input per line, in the order they would be asked for:
+/// - a move is `d` to draw, or the (1-based) number of the card to play,
+/// - anything said is the same as in the terminal, eg. `thank you. mao`, and an empty line is silence,
+/// - whether to play another round is `y` or `n`,
+/// - a new rule is two lines, the event and then the action, eg. `value is 3` and `say three`, or one line written like in a rules file, eg. `on value 3 say "three"`. To remove or modify a rule instead, it's a line with `remove` and then the rule, or `modify` and then the old rule and the new rule (`add` can go before a new rule too),
+/// - reordering the priority is the actions to go first, eg. `skip, reverse`, and an empty line keeps it.
+///
+/// Lines starting with `#` are comments and are skipped.
+#[derive(Debug)]
+pub struct ScriptedInterface {
+    inputs: VecDeque<(usize, String)>, // (line number, input)
+    notices: Vec<Notice>,
+}
+
+impl ScriptedInterface {
+    /// Creates a new `ScriptedInterface` from the text of a script.
+    pub fn new(script: &str) -> Self {
+        Self {
+            inputs: script
+                .lines()
+                .enumerate()
+                .filter(|(_, line)| !line.trim_start().starts_with('#'))
+                .map(|(i, line)| (i + 1, line.to_string()))
+                .collect(),
+            notices: vec![],
+        }
+    }
+
+    /// Creates a new `ScriptedInterface` from the script in the file at `path`.
+    pub fn load<P: AsRef<Path>>(path: P) -> std::io::Result<Self> {
+        Ok(Self::new(&std::fs::read_to_string(path)?))
+    }
+
+    /// Returns every notice the game has given so far, in order.
+    pub fn notices(&self) -> &Vec<Notice> {
+        &self.notices
+    }
+
+    /// Returns the number of inputs left in the script.
+    pub fn remaining(&self) -> usize {
+        self.inputs.len()
+    }
+
+    /// Takes the next input from the script, or returns `Err` if there is none left.
+    fn next_input(&mut self) -> RenderResult<(usize, String)> {
+        self.inputs.pop_front().ok_or(RenderError::ScriptEnded)
+    }
+
+    /// Reads a rule from the script, with the event in `input` (from line `line`), and the action on the next line. If `input` is a whole rule, like in a rules file, it's the whole rule instead.
+    fn rule_from(&mut self, (line, input): (usize, String)) -> RenderResult<Rule> {
+        if input.trim_start().to_lowercase().starts_with("on ") {
+            return Rule::parse(&input).map_err(|issue| RenderError::ScriptInvalid(line, issue));
+        }
+
+        let event =
+            Event::parse(&input).map_err(|issue| RenderError::ScriptInvalid(line, issue))?;
+
+        let (line, input) = self.next_input()?;
+        let action =
+            Action::parse(&input).map_err(|issue| RenderError::ScriptInvalid(line, issue))?;
+
+        Ok(Rule::new(event, action))
+    }
+
+    /// Reads a rule from the next two lines of the script.
+    fn next_rule(&mut self) -> RenderResult<Rule> {
+        let input = self.next_input()?;
+        self.rule_from(input)
+    }
+}
+
+impl PlayerInterface for ScriptedInterface {
+    fn notify(&mut self, _view: &PlayerView, notice: Notice) -> RenderResult<()> {
+        self.notices.push(notice);
+        Ok(())
+    }
+
+    fn request_move(&mut self, view: &PlayerView) -> RenderResult<Move> {
+        let (line, input) = self.next_input()?;
+
+        match input.trim() {
+            "D" | "d" => Ok(Move::Draw),
+            card_id => match card_id.parse::<usize>() {
+                Ok(n) if n >= 1 && n <= view.hand().len() => Ok(Move::Play(n - 1)),
+                _ => Err(RenderError::ScriptInvalid(
+                    line,
+                    format!("'{}' is not a move", card_id),
+                )),
+            },
+        }
+    }
+
+    fn request_speech(
+        &mut self,
+        _view: &PlayerView,
+        _did_draw: bool,
+        _action_card: Card,
+    ) -> RenderResult<Vec<String>> {
+        Ok(parse_quotes(&self.next_input()?.1))
+    }
+
+    fn request_penalty_speech(
+        &mut self,
+        _view: &PlayerView,
+        _report: &MistakeReport,
+    ) -> RenderResult<Vec<String>> {
+        Ok(parse_quotes(&self.next_input()?.1))
+    }
+
+    fn confirm_next_round(&mut self, _view: &PlayerView) -> RenderResult<bool> {
+        let (line, input) = self.next_input()?;
+
+        match input.trim().to_lowercase().as_str() {
+            "y" | "yes" => Ok(true),
+            "n" | "no" => Ok(false),
+            answer => Err(RenderError::ScriptInvalid(
+                line,
+                format!("'{}' is not y or n", answer),
+            )),
+        }
+    }
+
+    fn request_rule(&mut self, _view: &PlayerView, _winner: usize) -> RenderResult<RuleChange> {
+        let input = self.next_input()?;
+
+        match input.1.trim().to_lowercase().as_str() {
+            "add" => self.next_rule().map(RuleChange::Add),
+            "remove" => self.next_rule().map(RuleChange::Remove),
+            "modify" => Ok(RuleChange::Modify {
+                old: self.next_rule()?,
+                new: self.next_rule()?,
+            }),
+            _ => self.rule_from(input).map(RuleChange::Add),
+        }
+    }
+
+    fn request_priority(
+        &mut self,
+        view: &PlayerView,
+        _winner: usize,
+    ) -> RenderResult<Option<Priority>> {
+        let (line, input) = self.next_input()?;
+        if input.trim().is_empty() {
+            return Ok(None);
+        }
+
+        view.priority()
+            .parse_reorder(&input)
+            .map(Some)
+            .map_err(|issue| RenderError::ScriptInvalid(line, issue))
+    }
+}
@@ -0,0 +1,126 @@
+/*
+ * The "TableInterface" lets bots and people play the same game.
+ * Every decision is passed on to whoever is sitting in the seat
+ * it's asked of, and the people share one interface between
+ * them (eg. the terminal), like they would without any bots.
+ */
+
+use super::bot::BotKind;
+use super::{MistakeReport, Move, Notice, PlayerInterface};
+use crate::card::Card;
+use crate::player::Player;
+use crate::render::engine::RenderResult;
+use crate::rule::priority::Priority;
+use crate::rule::RuleChange;
+use crate::view::PlayerView;
+
+/// A `PlayerInterface` that asks each player's own bot for their decisions, or the shared `people` interface if they aren't a bot.
+pub struct TableInterface<'a> {
+    people: Option<&'a mut dyn PlayerInterface>,
+    bots: Vec<Option<Box<dyn PlayerInterface>>>, // One for each seat, `None` if a person is sitting there
+}
+
+impl<'a> TableInterface<'a> {
+    /// Creates a new `TableInterface` for `players`, where the bots make their random choices with `seed`, and everything else is asked of `people`.
+    pub fn new(players: &[Player], seed: u64, people: &'a mut dyn PlayerInterface) -> Self {
+        Self {
+            people: Some(people),
+            bots: Self::seat_bots(players, seed, false),
+        }
+    }
+
+    /// Creates a new `TableInterface` for `players` with nobody watching, where every seat is played by a bot. Seats that aren't already a bot are played by an easy one.
+    pub fn bots_only(players: &[Player], seed: u64) -> Self {
+        Self {
+            people: None,
+            bots: Self::seat_bots(players, seed, true),
+        }
+    }
+
+    /// Creates the bots for every seat played by one, and for everyone else too if `fill` is set.
+    fn seat_bots(
+        players: &[Player],
+        seed: u64,
+        fill: bool,
+    ) -> Vec<Option<Box<dyn PlayerInterface>>> {
+        players
+            .iter()
+            .enumerate()
+            .map(|(i, player)| {
+                let kind = match (player.bot_kind(), fill) {
+                    (Some(kind), _) => kind,
+                    (None, true) => BotKind::Easy,
+                    (None, false) => return None,
+                };
+                // Each bot gets its own seed, so two bots of the same kind don't make the same choices.
+                Some(kind.interface(seed.wrapping_add(i as u64)))
+            })
+            .collect()
+    }
+
+    /// Returns the interface making the decisions for `seat`.
+    fn seat(&mut self, seat: usize) -> &mut dyn PlayerInterface {
+        match self.bots.get_mut(seat) {
+            Some(Some(bot)) => bot.as_mut(),
+            _ => *self
+                .people
+                .as_mut()
+                .expect("every seat without a bot has a person sitting in it"),
+        }
+    }
+}
+
+impl<'a> PlayerInterface for TableInterface<'a> {
+    fn notify(&mut self, view: &PlayerView, notice: Notice) -> RenderResult<()> {
+        for bot in self.bots.iter_mut().flatten() {
+            bot.notify(view, notice.clone())?;
+        }
+
+        match &mut self.people {
+            Some(people) => people.notify(view, notice),
+            None => Ok(()),
+        }
+    }
+
+    fn request_move(&mut self, view: &PlayerView) -> RenderResult<Move> {
+        self.seat(view.player_index()).request_move(view)
+    }
+
+    fn request_speech(
+        &mut self,
+        view: &PlayerView,
+        did_draw: bool,
+        action_card: Card,
+    ) -> RenderResult<Vec<String>> {
+        self.seat(view.player_index())
+            .request_speech(view, did_draw, action_card)
+    }
+
+    fn request_penalty_speech(
+        &mut self,
+        view: &PlayerView,
+        report: &MistakeReport,
+    ) -> RenderResult<Vec<String>> {
+        self.seat(view.player_index())
+            .request_penalty_speech(view, report)
+    }
+
+    fn confirm_next_round(&mut self, view: &PlayerView) -> RenderResult<bool> {
+        match &mut self.people {
+            Some(people) => people.confirm_next_round(view),
+            None => Ok(true),
+        }
+    }
+
+    fn request_rule(&mut self, view: &PlayerView, winner: usize) -> RenderResult<RuleChange> {
+        self.seat(winner).request_rule(view, winner)
+    }
+
+    fn request_priority(
+        &mut self,
+        view: &PlayerView,
+        winner: usize,
+    ) -> RenderResult<Option<Priority>> {
+        self.seat(winner).request_priority(view, winner)
+    }
+}
@@ -0,0 +1,184 @@
+/*
+ * The "TerminalInterface" is the original way of playing:
+ * everybody shares one terminal, each decision is asked for
+ * by rendering a screen and reading a line from stdin.
+ */
+
+use super::{parse_quotes, MistakeReport, Move, Notice, PlayerInterface};
+use crate::card::Card;
+use crate::render::engine::{RenderResult, Screen};
+use crate::render::play_more_confirm_screen::PlayMoreConfirmScreen;
+use crate::render::play_screen::{CreateRuleState, PlayScreen, TurnState};
+use crate::rule::priority::Priority;
+use crate::rule::{Action, Event, Rule, RuleChange};
+use crate::view::PlayerView;
+
+/// A `PlayerInterface` that renders every screen to the terminal and reads the players' input from stdin.
+#[derive(Debug, Default)]
+pub struct TerminalInterface;
+
+impl PlayerInterface for TerminalInterface {
+    fn notify(&mut self, view: &PlayerView, notice: Notice) -> RenderResult<()> {
+        PlayScreen::from(notice).render_then_wait(Some(view))
+    }
+
+    fn request_move(&mut self, view: &PlayerView) -> RenderResult<Move> {
+        loop {
+            let card_id = PlayScreen::Turn(TurnState::Action).render_then_input(Some(view))?;
+
+            match card_id.trim() {
+                "D" | "d" => return Ok(Move::Draw),
+                cmd if cmd.starts_with("save ") => {
+                    return Ok(Move::Save(cmd["save ".len()..].trim().to_string()))
+                }
+                card_id => {
+                    if let Ok(n) = card_id.parse::<usize>() {
+                        if n >= 1 && n <= view.hand().len() {
+                            return Ok(Move::Play(n - 1));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn request_speech(
+        &mut self,
+        view: &PlayerView,
+        did_draw: bool,
+        action_card: Card,
+    ) -> RenderResult<Vec<String>> {
+        PlayScreen::Turn(TurnState::Speak(did_draw, action_card))
+            .render_then_input(Some(view))
+            .map(|data| parse_quotes(&data))
+    }
+
+    fn request_penalty_speech(
+        &mut self,
+        view: &PlayerView,
+        report: &MistakeReport,
+    ) -> RenderResult<Vec<String>> {
+        PlayScreen::Mistake(report.clone())
+            .render_then_input(Some(view))
+            .map(|data| parse_quotes(&data))
+    }
+
+    fn confirm_next_round(&mut self, view: &PlayerView) -> RenderResult<bool> {
+        Ok(loop {
+            let res = PlayMoreConfirmScreen.render_then_input(Some(view))?;
+            match res.trim().to_lowercase().as_str() {
+                "y" | "yes" | "ok" | "play" | "continue" => break true,
+                "n" | "no" | "quit" | "end" | "stop" => break false,
+                _ => continue,
+            }
+        })
+    }
+
+    fn request_rule(&mut self, view: &PlayerView, winner: usize) -> RenderResult<RuleChange> {
+        let mut format_issue = None;
+
+        let choice = loop {
+            let choice = PlayScreen::CreateRule {
+                winner,
+                format_issue: format_issue.take(),
+                state: CreateRuleState::ChooseChange,
+            }
+            .render_then_input(Some(view))?
+            .trim()
+            .to_lowercase();
+
+            match choice.as_str() {
+                "" | "add" | "remove" | "modify" => break choice,
+                _ => format_issue = Some(format!("'{}' is not add, remove or modify", choice)),
+            }
+        };
+
+        Ok(match choice.as_str() {
+            "remove" => RuleChange::Remove(request_rule_parts(view, winner, true)?),
+            "modify" => RuleChange::Modify {
+                old: request_rule_parts(view, winner, true)?,
+                new: request_rule_parts(view, winner, false)?,
+            },
+            _ => RuleChange::Add(request_rule_parts(view, winner, false)?),
+        })
+    }
+
+    fn request_priority(
+        &mut self,
+        view: &PlayerView,
+        winner: usize,
+    ) -> RenderResult<Option<Priority>> {
+        let mut format_issue = None;
+
+        loop {
+            let order = PlayScreen::CreateRule {
+                winner,
+                format_issue: format_issue.take(),
+                state: CreateRuleState::Reorder,
+            }
+            .render_then_input(Some(view))?;
+
+            if order.trim().is_empty() {
+                return Ok(None);
+            }
+            match view.priority().parse_reorder(&order) {
+                Ok(priority) => return Ok(Some(priority)),
+                Err(issue) => format_issue = Some(issue),
+            }
+        }
+    }
+}
+
+/// Asks `winner` for a rule's event and then its action, for a new rule, or to describe an `existing` one. The whole rule can also be typed in as the event, written like in a rules file (see `Rule::parse`).
+fn request_rule_parts(view: &PlayerView, winner: usize, existing: bool) -> RenderResult<Rule> {
+    let (event_state, action_state) = if existing {
+        (
+            CreateRuleState::DescribeEvent,
+            CreateRuleState::DescribeAction,
+        )
+    } else {
+        (CreateRuleState::MakeEvent, CreateRuleState::MakeAction)
+    };
+    let mut format_issue = None;
+
+    let event = loop {
+        let event_str = PlayScreen::CreateRule {
+            winner,
+            format_issue: format_issue.take(),
+            state: event_state,
+        }
+        .render_then_input(Some(view))?;
+
+        // A whole rule can be typed in at once, like in a rules file.
+        if event_str.trim_start().to_lowercase().starts_with("on ") {
+            match Rule::parse(&event_str) {
+                Ok(rule) => return Ok(rule),
+                Err(issue) => {
+                    format_issue = Some(issue);
+                    continue;
+                }
+            }
+        }
+
+        match Event::parse(&event_str) {
+            Ok(event) => break event,
+            Err(issue) => format_issue = Some(issue),
+        }
+    };
+
+    let action = loop {
+        let action_str = PlayScreen::CreateRule {
+            winner,
+            format_issue: format_issue.take(),
+            state: action_state,
+        }
+        .render_then_input(Some(view))?;
+
+        match Action::parse(&action_str) {
+            Ok(action) => break action,
+            Err(issue) => format_issue = Some(issue),
+        }
+    };
+
+    Ok(Rule::new(event, action))
+}
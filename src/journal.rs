@@ -0,0 +1,191 @@
+/*
+ * The "Journal" keeps a record of everything that happens in
+ * a game as typed "GameEvent"s, so it can be looked through
+ * after the game. Every event can also be passed on to an
+ * "EventSink" as it happens, eg. to write a log file.
+ */
+
+use crate::card::Card;
+use crate::render::play_screen::RuleActionResult;
+use crate::rule::priority::Priority;
+use crate::rule::Rule;
+use serde::{Deserialize, Serialize};
+use std::fmt::Debug;
+use std::io::Write;
+
+/// Something that happened in the game. Players are referred to by their index.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum GameEvent {
+    /// Round number `round` was dealt.
+    RoundStarted { round: usize },
+    /// `player` played `card`, which may or may not have been a legal play.
+    CardPlayed {
+        player: usize,
+        card: Card,
+        legal: bool,
+    },
+    /// `player` drew `card` from the draw pile on their turn. It's `None` when the card is face down (see `GameEvent::seen_by`).
+    CardDrawn { player: usize, card: Option<Card> },
+    /// A rule's action was applied after `player`'s turn.
+    RuleTriggered {
+        player: usize,
+        result: RuleActionResult,
+    },
+    /// `player` was penalized for the mistakes they just made, and dealt `drawn`.
+    Penalty {
+        player: usize,
+        /// "Incorrect use of _"s.
+        incs: Vec<String>,
+        /// "Failure to say _"s.
+        fails: Vec<String>,
+        /// If it was for playing an illegal card.
+        card_error: bool,
+        /// Face down cards are `None`.
+        drawn: Vec<Option<Card>>,
+    },
+    /// The draw pile ran low in round `round`, so the `cards` in the used pile under its top card were shuffled back into it.
+    DiscardsReshuffled { round: usize, cards: usize },
+    /// The draw pile ran low in round `round`, even with the used pile shuffled back into it, so a new shuffled deck was added to it.
+    DeckAdded { round: usize },
+    /// `player` got rid of all their cards.
+    RoundWon { round: usize, player: usize },
+    /// `player` added `rule` to the game.
+    RuleCreated { player: usize, rule: Rule },
+    /// `player` removed `rule` from the game.
+    RuleRemoved { player: usize, rule: Rule },
+    /// `player` replaced the rule `old` with `new`.
+    RuleModified { player: usize, old: Rule, new: Rule },
+    /// `player` reordered the priority the rules' actions are applied in.
+    PriorityChanged { player: usize, priority: Priority },
+}
+
+impl GameEvent {
+    /// Returns the event as `viewer` saw it happen, or as everybody did if it's `None`. Cards dealt to anyone else are turned face down, and changes to the rules are only seen by whoever made them, since the rules are secret.
+    pub fn seen_by(&self, viewer: Option<usize>) -> Option<GameEvent> {
+        let hidden = |player: &usize| viewer != Some(*player);
+
+        Some(match self {
+            GameEvent::CardDrawn { player, .. } if hidden(player) => GameEvent::CardDrawn {
+                player: *player,
+                card: None,
+            },
+            GameEvent::Penalty {
+                player,
+                incs,
+                fails,
+                card_error,
+                drawn,
+            } if hidden(player) => GameEvent::Penalty {
+                player: *player,
+                incs: incs.clone(),
+                fails: fails.clone(),
+                card_error: *card_error,
+                drawn: vec![None; drawn.len()],
+            },
+            GameEvent::RuleTriggered { player, result } => GameEvent::RuleTriggered {
+                player: *player,
+                result: result.seen_by(viewer),
+            },
+            GameEvent::RuleCreated { player, .. }
+            | GameEvent::RuleRemoved { player, .. }
+            | GameEvent::RuleModified { player, .. }
+                if hidden(player) =>
+            {
+                return None
+            }
+            event => event.clone(),
+        })
+    }
+}
+
+/// Something that is given every event in the game as it happens.
+pub trait EventSink: Debug {
+    fn record(&mut self, event: &GameEvent);
+}
+
+/// An `EventSink` that writes each event as JSON on its own line to a writer, such as a file.
+#[derive(Debug)]
+pub struct WriterSink<W: Write + Debug> {
+    writer: W,
+}
+
+impl<W: Write + Debug> WriterSink<W> {
+    /// Creates a new `WriterSink` writing to `writer`.
+    pub fn new(writer: W) -> Self {
+        Self { writer }
+    }
+}
+
+impl<W: Write + Debug> EventSink for WriterSink<W> {
+    fn record(&mut self, event: &GameEvent) {
+        // A log that can't be written shouldn't stop the game.
+        if let Ok(json) = serde_json::to_string(event) {
+            let _ = writeln!(self.writer, "{}", json);
+        }
+    }
+}
+
+/// A record of every `GameEvent` in a game, in the order they happened.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Journal {
+    events: Vec<GameEvent>,
+    #[serde(skip)]
+    sink: Option<Box<dyn EventSink>>,
+}
+
+impl Journal {
+    /// Creates a new, empty `Journal`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `event` to the journal, and passes it on to the sink if there is one.
+    pub fn record(&mut self, event: GameEvent) {
+        if let Some(sink) = &mut self.sink {
+            sink.record(&event);
+        }
+
+        self.events.push(event);
+    }
+
+    /// Sets where events are passed on to as they happen.
+    pub fn set_sink(&mut self, sink: Box<dyn EventSink>) {
+        self.sink = Some(sink);
+    }
+
+    /// Returns all the events so far, in order.
+    pub fn events(&self) -> &[GameEvent] {
+        &self.events
+    }
+
+    /// Returns the index of the event the current round started with, or 0 before the first round.
+    pub fn round_start(&self) -> usize {
+        self.events
+            .iter()
+            .rposition(|event| matches!(event, GameEvent::RoundStarted { .. }))
+            .unwrap_or(0)
+    }
+
+    /// Returns the events since the current round started, starting with the `RoundStarted`, in order.
+    pub fn round_events(&self) -> &[GameEvent] {
+        &self.events[self.round_start()..]
+    }
+
+    /// Iterates over all the events that involve `player`.
+    pub fn events_of(&self, player: usize) -> impl Iterator<Item = &GameEvent> {
+        self.events.iter().filter(move |event| match event {
+            GameEvent::RoundStarted { .. }
+            | GameEvent::DiscardsReshuffled { .. }
+            | GameEvent::DeckAdded { .. } => false,
+            GameEvent::CardPlayed { player: p, .. }
+            | GameEvent::CardDrawn { player: p, .. }
+            | GameEvent::RuleTriggered { player: p, .. }
+            | GameEvent::Penalty { player: p, .. }
+            | GameEvent::RoundWon { player: p, .. }
+            | GameEvent::RuleCreated { player: p, .. }
+            | GameEvent::RuleRemoved { player: p, .. }
+            | GameEvent::RuleModified { player: p, .. }
+            | GameEvent::PriorityChanged { player: p, .. } => *p == player,
+        })
+    }
+}
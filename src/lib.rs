@@ -8,6 +8,12 @@
 pub mod card;
 pub mod deck;
 pub mod game;
+pub mod interface;
+pub mod journal;
+pub mod net;
 pub mod player;
 pub mod render;
+pub mod replay;
 pub mod rule;
+pub mod sim;
+pub mod view;
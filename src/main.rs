@@ -6,13 +6,25 @@
  * game, handling any render errors.
  */
 
+use mao::game::config::GameConfig;
 use mao::game::Game;
+use mao::interface::recording::RecordingInterface;
+use mao::interface::replay::ReplayInterface;
+use mao::interface::table::TableInterface;
+use mao::interface::terminal::TerminalInterface;
+use mao::journal::WriterSink;
+use mao::net::{Client, RemoteTable};
 use mao::player::Player;
 use mao::render::ansi::{ANSIColor, ANSI_STYLE_RESET};
-use mao::render::engine::{RenderResult, Screen};
+use mao::render::engine::{RenderError, RenderResult, Screen};
 use mao::render::error_handling;
 use mao::render::name_select_screen::select_names;
+use mao::render::replay_screen::view_replay;
 use mao::render::title_screen::TitleScreen;
+use mao::replay::Replay;
+use mao::rule::dsl;
+use mao::sim::{simulate, SimConfig};
+use rand::random;
 
 //  _  _ ____ ____    ____ ____ _  _ ____
 //  |\/| |__| |  |    | __ |__| |\/| |___
@@ -36,6 +48,34 @@ fn main() {
 
 /// Actually runs the game, and returns `Ok(())` if the game went well, or a `Err<RenderError>` if there was a rendering error.
 fn main_r() -> RenderResult<()> {
+    // `mao sim` plays lots of games between bots, and prints the stats of them.
+    if std::env::args().nth(1).as_deref() == Some("sim") {
+        sim_r();
+        return Ok(());
+    }
+
+    // `mao host` runs a game for players joining over the network, `mao join <addr>` joins one.
+    match std::env::args().nth(1).as_deref() {
+        Some("host") => return host_r(),
+        Some("join") => return join_r(),
+        _ => {}
+    }
+
+    // `mao replay <file>` steps through a recorded game instead of playing one.
+    if std::env::args().nth(1).as_deref() == Some("replay") {
+        return match std::env::args().nth(2).map(Replay::load) {
+            Some(Ok(replay)) => replay_r(replay),
+            Some(Err(err)) => {
+                eprintln!("Failed to load the replay: {err}");
+                Ok(())
+            }
+            None => {
+                eprintln!("Usage: mao replay <file>");
+                Ok(())
+            }
+        };
+    }
+
     (TitleScreen {
         show_instructions: false,
     })
@@ -45,10 +85,43 @@ fn main_r() -> RenderResult<()> {
     })
     .render_then_wait(None)?;
 
-    let players: Vec<_> = select_names()?.into_iter().map(Player::new).collect();
+    // A saved game already has its players, so only ask for names when starting a new one.
+    let loaded = arg_value("--load").and_then(|path| match Game::load(&path) {
+        Ok(game) => Some(game),
+        Err(err) => {
+            eprintln!("Failed to load '{path}', starting a new game instead: {err}");
+            None
+        }
+    });
+
+    let mut game = match loaded {
+        Some(game) => game,
+        None => {
+            let players = select_names()?;
+
+            let seed = arg_value("--seed").and_then(|seed| seed.parse().ok());
+            let mut game = Game::with_config(players, seed.unwrap_or_else(random), game_config());
+            if has_flag("--random-priority") {
+                game.shuffle_priority();
+            }
+            use_rules_file(&mut game);
+            game
+        }
+    };
+
+    // Log everything that happens to a file, if asked to.
+    if let Some(path) = arg_value("--journal") {
+        if let Ok(file) = std::fs::File::create(path) {
+            game.set_event_sink(Box::new(WriterSink::new(file)));
+        }
+    }
 
-    let mut game = Game::new(players);
-    println!("Rule Map: {:#?}", game.rule_map());
+    println!(
+        "{}Seed: {}{}",
+        ANSIColor::LightGreen.fg(),
+        game.seed(),
+        ANSI_STYLE_RESET
+    );
 
     println!(
         "{}Total Cards: {}{}",
@@ -57,7 +130,241 @@ fn main_r() -> RenderResult<()> {
         ANSI_STYLE_RESET
     );
 
-    game.play()?;
+    // Only a new game can be recorded, as a replay starts from the beginning of the game.
+    let record = arg_value("--record").filter(|_| {
+        let new = game.round_n() == 0;
+        if !new {
+            eprintln!("A loaded game can't be recorded, so no replay will be saved.");
+        }
+        new
+    });
+
+    let result = match &record {
+        Some(path) => {
+            let seed = game.seed();
+            let players = game.players().iter().map(|p| p.name().clone()).collect();
+            let priority = game.priority().clone();
+            let rules = game.rule_map().rules().cloned().collect();
+            let config = game.config().clone();
+
+            let mut terminal = TerminalInterface;
+            let mut table = TableInterface::new(game.players(), game.seed(), &mut terminal);
+            let mut recording = RecordingInterface::new(&mut table);
+            let result = game.play(&mut recording);
+
+            let replay = Replay::new(seed, players, recording.into_inputs())
+                .with_priority(priority)
+                .with_rules(rules)
+                .with_config(config);
+            if let Err(err) = replay.save(path) {
+                eprintln!("Failed to save the replay to '{path}': {err}");
+            }
+            result
+        }
+        None => {
+            let mut terminal = TerminalInterface;
+            let mut table = TableInterface::new(game.players(), game.seed(), &mut terminal);
+            game.play(&mut table)
+        }
+    };
+
+    // Keep the game (and all of its rules) for next time, if asked to.
+    if let Some(path) = arg_value("--save") {
+        if let Err(err) = game.save(&path) {
+            eprintln!("Failed to save the game to '{path}': {err}");
+        }
+    }
+
+    result.map(|_| ())
+}
+
+/// Plays `replay` back, keeping every screen along the way, then lets the user step through them.
+fn replay_r(replay: Replay) -> RenderResult<()> {
+    let mut game = replay.new_game();
+    let mut interface = ReplayInterface::new(replay.inputs).capture_frames();
+
+    // The replay ends by running out of inputs, unless the players stopped playing.
+    match game.play(&mut interface) {
+        Ok(_) | Err(RenderError::ScriptEnded) => {}
+        Err(err) => return Err(err),
+    }
+
+    view_replay(&interface.into_frames())
+}
+
+/// Hosts a game on `--addr` (by default 0.0.0.0:7878) for `--players` players (by default 2) to join, and plays it until the first player to join stops.
+fn host_r() -> RenderResult<()> {
+    let addr = arg_value("--addr").unwrap_or_else(|| "0.0.0.0:7878".to_string());
+    let players = arg_value("--players")
+        .and_then(|n| n.parse().ok())
+        .unwrap_or(2usize)
+        .max(2);
+
+    let listener =
+        std::net::TcpListener::bind(&addr).map_err(|err| RenderError::Network(err.to_string()))?;
+    println!("Waiting for {players} players to join on {addr}...");
+
+    let (mut table, names) = RemoteTable::accept(&listener, players)?;
+    println!("Playing with {}", names.join(", "));
+
+    let players = names.into_iter().map(Player::new).collect();
+    let seed = arg_value("--seed").and_then(|seed| seed.parse().ok());
+    let mut game = Game::with_config(players, seed.unwrap_or_else(random), game_config());
+    if has_flag("--random-priority") {
+        game.shuffle_priority();
+    }
+    use_rules_file(&mut game);
+
+    let result = game.play(&mut table);
+    table.finish();
+
+    if let Some(path) = arg_value("--save") {
+        if let Err(err) = game.save(&path) {
+            eprintln!("Failed to save the game to '{path}': {err}");
+        }
+    }
+
+    result.map(|_| ())
+}
+
+/// Joins the game hosted at the address after `join`, as `--name` (or whatever name is typed in), and plays it in the terminal.
+fn join_r() -> RenderResult<()> {
+    let Some(addr) = std::env::args().nth(2) else {
+        eprintln!("Usage: mao join <addr> [--name <name>]");
+        return Ok(());
+    };
+
+    let name = match arg_value("--name") {
+        Some(name) => name,
+        None => {
+            println!("Enter your name:");
+            let mut name = String::new();
+            std::io::stdin()
+                .read_line(&mut name)
+                .map_err(|_| RenderError::InputFailed)?;
+            name.trim().to_string()
+        }
+    };
+
+    let mut client = Client::connect(addr.as_str(), &name)?;
+    println!(
+        "Joined as player {}, waiting for the game to start...",
+        client.seat() + 1
+    );
+    client.play(&mut TerminalInterface)
+}
+
+/// Runs a simulation set up from the command line, eg. `mao sim --games 5000 --bots easy,hard,expert`, and prints the stats.
+///
+/// `--load <file>` plays with the rules of a saved game.
+fn sim_r() {
+    let mut config = SimConfig {
+        game: game_config(),
+        ..SimConfig::default()
+    };
+
+    if let Some(games) = arg_value("--games").and_then(|n| n.parse().ok()) {
+        config.games = games;
+    }
+    if let Some(rounds) = arg_value("--rounds").and_then(|n| n.parse().ok()) {
+        config.rounds = rounds;
+    }
+    if let Some(seed) = arg_value("--seed").and_then(|n| n.parse().ok()) {
+        config.seed = seed;
+    }
+    if let Some(max_turns) = arg_value("--max-turns").and_then(|n| n.parse().ok()) {
+        config.max_turns = max_turns;
+    }
+    if let Some(bots) = arg_value("--bots") {
+        let bots: Option<Vec<_>> = bots
+            .split(',')
+            .map(|kind| format!("bot:{kind}").parse().ok())
+            .collect();
+        match bots {
+            Some(bots) if bots.len() >= 2 => config.bots = bots,
+            _ => eprintln!("--bots should be at least 2 of easy, hard or expert, eg. easy,hard"),
+        }
+    }
+    if let Some(path) = arg_value("--load") {
+        match Game::load(&path) {
+            Ok(game) => config.rule_map = Some(game.rule_map().clone()),
+            Err(err) => eprintln!("Failed to load '{path}', using the default rules: {err}"),
+        }
+    }
+
+    print!("{}", simulate(&config));
+}
+
+/// Returns the settings for a new game given on the command line, eg. `--hand-size 5 --deck piquet --jokers 2 --decks 2 --starting-player rotate --play-policy suit --no-default-rules --no-extra-decks`. Anything not given is the same as in the usual game of Mao.
+fn game_config() -> GameConfig {
+    let mut config = GameConfig::default();
+
+    if let Some(hand_size) = arg_value("--hand-size").and_then(|n| n.parse().ok()) {
+        config = config.with_hand_size(hand_size);
+    }
+    if let Some(deck) = arg_value("--deck") {
+        match deck.parse() {
+            Ok(deck) => config = config.with_deck(deck),
+            Err(_) => eprintln!("--deck should be one of standard, piquet or euchre"),
+        }
+    }
+    if let Some(jokers) = arg_value("--jokers").and_then(|n| n.parse().ok()) {
+        config = config.with_jokers(jokers);
+    }
+    if let Some(decks) = arg_value("--decks").and_then(|n| n.parse().ok()) {
+        config = config.with_decks(decks);
+    }
+    if let Some(starting_player) = arg_value("--starting-player") {
+        match starting_player.parse() {
+            Ok(starting_player) => config = config.with_starting_player(starting_player),
+            Err(_) => {
+                eprintln!("--starting-player should be one of first, random, rotate or winner")
+            }
+        }
+    }
+    if let Some(play_policy) = arg_value("--play-policy") {
+        match play_policy.parse() {
+            Ok(play_policy) => config = config.with_play_policy(play_policy),
+            Err(_) => eprintln!("--play-policy should be one of colour, suit or any"),
+        }
+    }
+    if has_flag("--no-default-rules") {
+        config = config.with_default_rules(false);
+    }
+    if has_flag("--no-extra-decks") {
+        config = config.with_add_decks(false);
+    }
+
+    config
+}
+
+/// Replaces the rules of a new `game` with the ones in the rules file given after `--rules`, if there is one (see `mao::rule::dsl`).
+fn use_rules_file(game: &mut Game) {
+    let Some(path) = arg_value("--rules") else {
+        return;
+    };
+
+    let rules = std::fs::read_to_string(&path)
+        .map_err(|err| err.to_string())
+        .and_then(|text| dsl::parse_rules(&text));
+    let result = rules.and_then(|rules| {
+        game.use_rules(rules)
+            .map_err(|_| "some of the rules conflict with each other".to_string())
+    });
+
+    if let Err(err) = result {
+        eprintln!("Failed to load the rules from '{path}', using the default rules instead: {err}");
+    }
+}
+
+/// Returns whether `flag` was given on the command line (eg. `--random-priority`).
+fn has_flag(flag: &str) -> bool {
+    std::env::args().any(|arg| arg == flag)
+}
 
-    Ok(())
+/// Returns the value given after `flag` on the command line (eg. `--seed 42`), if there is one.
+fn arg_value(flag: &str) -> Option<String> {
+    let args: Vec<String> = std::env::args().collect();
+    let i = args.iter().position(|arg| arg == flag)?;
+    args.get(i + 1).cloned()
 }
@@ -0,0 +1,346 @@
+/*
+ * Playing over the network. The host owns the "Game" and sits
+ * a "RemoteTable" at it, which sends every request to the
+ * player it's for. Each player joins with a "Client", which
+ * answers the requests with its own interface (eg. the
+ * terminal). Only "PlayerView"s are ever sent, so everybody
+ * only ever sees their own hand.
+ *
+ * Messages are JSON, one per line, both ways.
+ */
+
+use crate::card::Card;
+use crate::interface::{MistakeReport, Move, Notice, PlayerInterface};
+use crate::render::engine::{RenderError, RenderResult};
+use crate::replay::Input;
+use crate::rule::priority::Priority;
+use crate::rule::{Rule, RuleChange};
+use crate::view::PlayerView;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::io::{BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream, ToSocketAddrs};
+
+/// A message from the host to a player. Every view sent is only what that player is allowed to see.
+#[derive(Debug, Serialize, Deserialize)]
+pub enum ServerMessage {
+    /// The player has joined, and is sitting in `seat`.
+    Welcome {
+        seat: usize,
+    },
+    Notify {
+        view: PlayerView,
+        notice: Notice,
+    },
+    RequestMove {
+        view: PlayerView,
+    },
+    RequestSpeech {
+        view: PlayerView,
+        did_draw: bool,
+        action_card: Card,
+    },
+    RequestPenaltySpeech {
+        view: PlayerView,
+        report: MistakeReport,
+    },
+    ConfirmNextRound {
+        view: PlayerView,
+    },
+    RequestRule {
+        view: PlayerView,
+        winner: usize,
+    },
+    RequestPriority {
+        view: PlayerView,
+        winner: usize,
+    },
+    /// The host has stopped playing.
+    GameOver,
+}
+
+/// A message from a player to the host.
+#[derive(Debug, Serialize, Deserialize)]
+pub enum ClientMessage {
+    /// Asks to join the game as `name`.
+    Join { name: String },
+    /// The answer to the last request.
+    Answer(Input),
+}
+
+/// One end of a connection, sending and receiving messages a line at a time.
+#[derive(Debug)]
+struct Connection {
+    reader: BufReader<TcpStream>,
+    writer: TcpStream,
+}
+
+impl Connection {
+    fn new(stream: TcpStream) -> RenderResult<Self> {
+        Ok(Self {
+            writer: stream.try_clone().map_err(network)?,
+            reader: BufReader::new(stream),
+        })
+    }
+
+    fn send<T: Serialize>(&mut self, message: &T) -> RenderResult<()> {
+        let line = serde_json::to_string(message).map_err(network)?;
+        writeln!(self.writer, "{line}").map_err(network)
+    }
+
+    fn receive<T: DeserializeOwned>(&mut self) -> RenderResult<T> {
+        let mut line = String::new();
+        if self.reader.read_line(&mut line).map_err(network)? == 0 {
+            return Err(RenderError::Network("connection closed".to_string()));
+        }
+        serde_json::from_str(&line).map_err(network)
+    }
+}
+
+/// Turns any error into a `RenderError::Network`.
+fn network<E: std::fmt::Display>(err: E) -> RenderError {
+    RenderError::Network(err.to_string())
+}
+
+/// A `PlayerInterface` for the host, which asks every player for their decisions over the network.
+#[derive(Debug)]
+pub struct RemoteTable {
+    seats: Vec<Connection>, // In the order the players joined
+}
+
+impl RemoteTable {
+    /// Waits for `players` players to join on `listener`. Returns the table, and the names of the players in the order they joined.
+    pub fn accept(listener: &TcpListener, players: usize) -> RenderResult<(Self, Vec<String>)> {
+        let mut seats = vec![];
+        let mut names = vec![];
+
+        while seats.len() < players {
+            let (stream, _) = listener.accept().map_err(network)?;
+            let mut conn = Connection::new(stream)?;
+
+            // Somebody that doesn't say who they are isn't let in.
+            if let Ok(ClientMessage::Join { name }) = conn.receive() {
+                conn.send(&ServerMessage::Welcome { seat: seats.len() })?;
+                seats.push(conn);
+                names.push(name);
+            }
+        }
+
+        Ok((Self { seats }, names))
+    }
+
+    /// Tells every player the game is over.
+    pub fn finish(&mut self) {
+        for conn in &mut self.seats {
+            // Somebody who already left doesn't need telling.
+            let _ = conn.send(&ServerMessage::GameOver);
+        }
+    }
+
+    /// Sends `message` to the player in `seat`, and waits for their answer.
+    fn ask(&mut self, seat: usize, message: ServerMessage) -> RenderResult<Input> {
+        let conn = &mut self.seats[seat];
+        conn.send(&message)?;
+
+        match conn.receive()? {
+            ClientMessage::Answer(input) => Ok(input),
+            message => Err(RenderError::Network(format!(
+                "expected an answer, got {message:?}"
+            ))),
+        }
+    }
+}
+
+/// The error for a player answering with the wrong kind of decision.
+fn unexpected(input: Input) -> RenderError {
+    RenderError::Network(format!("unexpected answer {input:?}"))
+}
+
+/// Reads the rules in `change` again the way rules typed in would be, since a player could send any rule at all, even one that couldn't be typed in (eg. with a count of a million, or a message that can't be filled in).
+///
+/// Returns the change with the rules as they were read, so nothing but their events and actions are kept, or `None` if any of them is invalid.
+fn checked(change: RuleChange) -> Option<RuleChange> {
+    let check = |rule: Rule| Rule::parse(&rule.to_string()).ok();
+    Some(match change {
+        RuleChange::Add(rule) => RuleChange::Add(check(rule)?),
+        RuleChange::Remove(rule) => RuleChange::Remove(check(rule)?),
+        RuleChange::Modify { old, new } => RuleChange::Modify {
+            old: check(old)?,
+            new: check(new)?,
+        },
+    })
+}
+
+impl PlayerInterface for RemoteTable {
+    fn notify(&mut self, view: &PlayerView, notice: Notice) -> RenderResult<()> {
+        for conn in &mut self.seats {
+            conn.send(&ServerMessage::Notify {
+                view: view.clone(),
+                notice: notice.clone(),
+            })?;
+        }
+        Ok(())
+    }
+
+    fn request_move(&mut self, view: &PlayerView) -> RenderResult<Move> {
+        loop {
+            match self.ask(
+                view.player_index(),
+                ServerMessage::RequestMove { view: view.clone() },
+            )? {
+                // Only the host can save the game.
+                Input::Move(Move::Save(_)) => continue,
+                Input::Move(mov) => return Ok(mov),
+                input => return Err(unexpected(input)),
+            }
+        }
+    }
+
+    fn request_speech(
+        &mut self,
+        view: &PlayerView,
+        did_draw: bool,
+        action_card: Card,
+    ) -> RenderResult<Vec<String>> {
+        match self.ask(
+            view.player_index(),
+            ServerMessage::RequestSpeech {
+                view: view.clone(),
+                did_draw,
+                action_card,
+            },
+        )? {
+            Input::Speech(quotes) => Ok(quotes),
+            input => Err(unexpected(input)),
+        }
+    }
+
+    fn request_penalty_speech(
+        &mut self,
+        view: &PlayerView,
+        report: &MistakeReport,
+    ) -> RenderResult<Vec<String>> {
+        match self.ask(
+            view.player_index(),
+            ServerMessage::RequestPenaltySpeech {
+                view: view.clone(),
+                report: report.clone(),
+            },
+        )? {
+            Input::Speech(quotes) => Ok(quotes),
+            input => Err(unexpected(input)),
+        }
+    }
+
+    /// The first player to join decides whether to keep playing.
+    fn confirm_next_round(&mut self, view: &PlayerView) -> RenderResult<bool> {
+        match self.ask(0, ServerMessage::ConfirmNextRound { view: view.clone() })? {
+            Input::NextRound(again) => Ok(again),
+            input => Err(unexpected(input)),
+        }
+    }
+
+    fn request_rule(&mut self, view: &PlayerView, winner: usize) -> RenderResult<RuleChange> {
+        loop {
+            let change = match self.ask(
+                winner,
+                ServerMessage::RequestRule {
+                    view: view.clone(),
+                    winner,
+                },
+            )? {
+                Input::RuleChange(change) => checked(change),
+                input => return Err(unexpected(input)),
+            };
+
+            // A rule that couldn't have been typed in is asked for again.
+            if let Some(change) = change {
+                return Ok(change);
+            }
+        }
+    }
+
+    fn request_priority(
+        &mut self,
+        view: &PlayerView,
+        winner: usize,
+    ) -> RenderResult<Option<Priority>> {
+        loop {
+            match self.ask(
+                winner,
+                ServerMessage::RequestPriority {
+                    view: view.clone(),
+                    winner,
+                },
+            )? {
+                // A priority missing some of the actions would stop their rules from happening, so it's asked for again.
+                Input::Priority(Some(priority)) if !priority.is_complete() => continue,
+                Input::Priority(priority) => return Ok(priority),
+                input => return Err(unexpected(input)),
+            }
+        }
+    }
+}
+
+/// A player's connection to a host.
+#[derive(Debug)]
+pub struct Client {
+    conn: Connection,
+    seat: usize,
+}
+
+impl Client {
+    /// Joins the game hosted at `addr` as `name`.
+    pub fn connect<A: ToSocketAddrs>(addr: A, name: &str) -> RenderResult<Self> {
+        let mut conn = Connection::new(TcpStream::connect(addr).map_err(network)?)?;
+        conn.send(&ClientMessage::Join {
+            name: name.to_string(),
+        })?;
+
+        match conn.receive()? {
+            ServerMessage::Welcome { seat } => Ok(Self { conn, seat }),
+            message => Err(RenderError::Network(format!(
+                "expected a welcome, got {message:?}"
+            ))),
+        }
+    }
+
+    /// Returns the seat this player is sitting in.
+    pub fn seat(&self) -> usize {
+        self.seat
+    }
+
+    /// Answers everything the host asks with `interface`, until the host says the game is over.
+    pub fn play(&mut self, interface: &mut dyn PlayerInterface) -> RenderResult<()> {
+        loop {
+            let input = match self.conn.receive()? {
+                ServerMessage::Notify { view, notice } => {
+                    interface.notify(&view, notice)?;
+                    continue;
+                }
+                ServerMessage::RequestMove { view } => Input::Move(interface.request_move(&view)?),
+                ServerMessage::RequestSpeech {
+                    view,
+                    did_draw,
+                    action_card,
+                } => Input::Speech(interface.request_speech(&view, did_draw, action_card)?),
+                ServerMessage::RequestPenaltySpeech { view, report } => {
+                    Input::Speech(interface.request_penalty_speech(&view, &report)?)
+                }
+                ServerMessage::ConfirmNextRound { view } => {
+                    Input::NextRound(interface.confirm_next_round(&view)?)
+                }
+                ServerMessage::RequestRule { view, winner } => {
+                    Input::RuleChange(interface.request_rule(&view, winner)?)
+                }
+                ServerMessage::RequestPriority { view, winner } => {
+                    Input::Priority(interface.request_priority(&view, winner)?)
+                }
+                ServerMessage::GameOver => return Ok(()),
+                ServerMessage::Welcome { .. } => continue,
+            };
+
+            self.conn.send(&ClientMessage::Answer(input))?;
+        }
+    }
+}
@@ -6,13 +6,17 @@
  */
 
 use crate::card::Card;
-use crate::deck::Deck;
+use crate::deck::{Deck, DeckResult};
+use crate::interface::bot::BotKind;
+use serde::{Deserialize, Serialize};
 
 /// A struct representing a player in the game.
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Player {
     name: String,
     hand: Vec<Card>,
+    #[serde(default)]
+    bot: Option<BotKind>, // `None` if a person is playing
 }
 
 impl Player {
@@ -21,12 +25,22 @@ impl Player {
         Self {
             name,
             hand: Vec::new(),
+            bot: None,
         }
     }
 
-    /// Draws 1 card from `deck`. If `deck` is empty, it creates a new `Deck` and appends it to `deck`, then draws from it.
-    pub fn draw(&mut self, deck: &mut Deck) -> Card {
-        deck.deal(1, self)[0]
+    /// Creates a new `Player` with name `name`, who is played by a bot of kind `kind`.
+    pub fn bot(name: String, kind: BotKind) -> Self {
+        Self {
+            bot: Some(kind),
+            ..Self::new(name)
+        }
+    }
+
+    /// Draws 1 card from `deck`, and returns it. Returns `Err` if `deck` is empty.
+    pub fn draw(&mut self, deck: &mut Deck) -> DeckResult<Card> {
+        // `try_deal` only succeeds if it dealt all of the cards.
+        deck.try_deal(1, self).map(|cards| cards[0])
     }
 
     /// Plays the card at `card_index` from hand, and puts it on the top of `deck`.
@@ -47,6 +61,11 @@ impl Player {
         &self.name
     }
 
+    /// Returns the kind of bot playing as this player, or `None` if it's a person.
+    pub fn bot_kind(&self) -> Option<BotKind> {
+        self.bot
+    }
+
     /// Returns a mutable reference to this player's hand.
     pub fn mut_hand(&mut self) -> &mut Vec<Card> {
         &mut self.hand
@@ -10,4 +10,5 @@ pub mod img;
 pub mod name_select_screen;
 pub mod play_more_confirm_screen;
 pub mod play_screen;
+pub mod replay_screen;
 pub mod title_screen;
@@ -85,10 +85,10 @@ impl Default for ANSIStyle {
 impl std::fmt::Display for ANSIStyle {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         if self.is_fg {
-            write!(f, "{}", self.color.to_string(true))?;
+            write!(f, "{}", self.color.code(true))?;
         }
         if self.is_bg {
-            write!(f, "{}", self.color.to_string(false))?;
+            write!(f, "{}", self.color.code(false))?;
         }
         if self.is_bold {
             write!(f, "{}", ANSIModifier::Bold)?;
@@ -144,14 +144,14 @@ pub enum ANSIColor {
 
 impl ANSIColor {
     pub fn fg(&self) -> String {
-        self.to_string(true)
+        self.code(true)
     }
     pub fn bg(&self) -> String {
-        self.to_string(false)
+        self.code(false)
     }
 
     /// Gets the full ANSI code for this color.
-    fn to_string(&self, is_fg: bool) -> String {
+    fn code(&self, is_fg: bool) -> String {
         let id = match (is_fg, self) {
             (_, Self::RGB(r, g, b)) => {
                 // FG: ESC[38;2;⟨r⟩;⟨g⟩;⟨b⟩m
@@ -159,9 +159,9 @@ impl ANSIColor {
                 format!(
                     "{};2;{};{};{}",
                     if is_fg { "38" } else { "48" },
-                    r.to_string(),
-                    g.to_string(),
-                    b.to_string()
+                    r,
+                    g,
+                    b
                 )
             }
 
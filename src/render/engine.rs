@@ -4,15 +4,15 @@
  * to draw things on the screen such as rectangles, boxes, and text.
  */
 
-use crate::game::Game;
 use crate::render::ansi::{ANSIColor, ANSIStyle, ANSI_STYLE_RESET};
+use crate::view::PlayerView;
 use core::fmt::Debug;
 use std::io::{stdin, BufRead};
 use term_size;
 
 /// Render over the entire screen.
 fn print_framebuffer(fb: TextFrameBuffer) {
-    println!("\n\n\n\n\n\n\n{}", fb.to_string());
+    println!("\n\n\n\n\n\n\n{}", fb);
     // println!("\x1B[2J{}", fb.to_string()); // "\x1B[2J" is clear
 }
 
@@ -27,12 +27,23 @@ fn about_to_display<T: Debug>(_screen: &T) {
 pub trait Screen: Debug {
     /// Takes and subsequently populates a TextFrameBuffer with visual
     /// elements to be printed to the screen.
-    fn render_to_buffer(&self, fb: &mut TextFrameBuffer, game: Option<&Game>) -> RenderResult<()>;
+    fn render_to_buffer(
+        &self,
+        fb: &mut TextFrameBuffer,
+        view: Option<&PlayerView>,
+    ) -> RenderResult<()>;
+
+    /// Renders to a new TextFrameBuffer and returns it, without printing anything.
+    fn render_to_frame(&self, view: Option<&PlayerView>) -> RenderResult<TextFrameBuffer> {
+        let mut fb = TextFrameBuffer::new()?;
+        self.render_to_buffer(&mut fb, view)?;
+        Ok(fb)
+    }
 
-    fn render(&self, game: Option<&Game>) -> RenderResult<()> {
+    fn render(&self, view: Option<&PlayerView>) -> RenderResult<()> {
         about_to_render(&self);
         let mut fb = TextFrameBuffer::new()?;
-        self.render_to_buffer(&mut fb, game)?;
+        self.render_to_buffer(&mut fb, view)?;
         fb.set_input_prompt("".to_string());
         about_to_display(&self);
         print_framebuffer(fb);
@@ -40,10 +51,10 @@ pub trait Screen: Debug {
     }
 
     /// Like render, but a prompt is shown and stdin is read.
-    fn render_then_input(&self, game: Option<&Game>) -> RenderResult<String> {
+    fn render_then_input(&self, view: Option<&PlayerView>) -> RenderResult<String> {
         about_to_render(&self);
         let mut fb = TextFrameBuffer::new()?;
-        self.render_to_buffer(&mut fb, game)?;
+        self.render_to_buffer(&mut fb, view)?;
         about_to_display(&self);
         print_framebuffer(fb);
 
@@ -57,8 +68,8 @@ pub trait Screen: Debug {
     }
 
     /// Calls render_then_input, and voids the response.
-    fn render_then_wait(&self, game: Option<&Game>) -> RenderResult<()> {
-        self.render_then_input(game)?;
+    fn render_then_wait(&self, view: Option<&PlayerView>) -> RenderResult<()> {
+        self.render_then_input(view)?;
         Ok(())
     }
 }
@@ -68,6 +79,10 @@ pub enum RenderError {
     TerminalDimensionsBad,
     DrawOutOfBounds((usize, usize), (usize, usize), (usize, usize)),
     InputFailed,
+    ScriptEnded,
+    ScriptInvalid(usize, String),
+    ReplayMismatch(usize),
+    Network(String),
 }
 
 impl std::fmt::Display for RenderError {
@@ -79,6 +94,14 @@ impl std::fmt::Display for RenderError {
                 "Drawing out of bounds [pos({x},{y}), dim({w},{h}), allowedDim({maxw},{maxh})]!"
             ),
             Self::InputFailed => write!(f, "Failed to receive stdin input!"),
+            Self::ScriptEnded => write!(f, "Ran out of scripted input!"),
+            Self::ScriptInvalid(line, issue) => {
+                write!(f, "Invalid scripted input on line {line}: {issue}!")
+            }
+            Self::ReplayMismatch(n) => {
+                write!(f, "Replay doesn't match the game at input {n}!")
+            }
+            Self::Network(issue) => write!(f, "Network error: {issue}!"),
         }
     }
 }
@@ -89,6 +112,7 @@ pub type RenderResult<T> = Result<T, RenderError>;
 /// An FrabeBuffer like object which is a 2-dimensional vec of
 /// `char` to be drawn to the screen and `TextStyle` to apply
 /// at each point
+#[derive(Clone)]
 pub struct TextFrameBuffer {
     w: usize,
     h: usize,
@@ -125,7 +149,7 @@ impl TextFrameBuffer {
     /// Draw a **single line** of text (`"\n"` will break do not use)
     pub fn text(&mut self, txt: &str, x: usize, y: usize) -> RenderResult<()> {
         self.check_bounds(x, y, txt.len(), 1)?;
-        for (i, char) in txt.chars().into_iter().enumerate() {
+        for (i, char) in txt.chars().enumerate() {
             self.view[x + i][y] = char;
         }
         Ok(())
@@ -135,7 +159,7 @@ impl TextFrameBuffer {
         let lines = textwrap::wrap(txt, w);
         self.check_bounds(x, y, w, lines.len())?;
         for (j, line) in lines.into_iter().enumerate() {
-            for (i, char) in line.chars().into_iter().enumerate() {
+            for (i, char) in line.chars().enumerate() {
                 self.view[x + i][y + j] = char;
             }
         }
@@ -333,12 +357,11 @@ impl BoxDrawingProfile {
     }
 }
 
-impl std::string::ToString for TextFrameBuffer {
+impl std::fmt::Display for TextFrameBuffer {
     // This is the function that actually turns a `TextFrameBuffer` into a frame.
-    fn to_string(&self) -> String {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let w = self.w;
         let h = self.h;
-        dbg!((w, h));
         let mut txt = "".to_string();
         for y in 0..h {
             for x in 0..w {
@@ -359,10 +382,10 @@ impl std::string::ToString for TextFrameBuffer {
                 };
                 txt += format!("{style}{char}{style_release}",).as_str();
             }
-            txt += &"\n";
+            txt += "\n";
         }
         txt += self.input_prompt.as_str();
-        txt
+        write!(f, "{txt}")
     }
 }
 
@@ -13,7 +13,7 @@ use std::collections::HashMap;
 
 /// Represents a text-based-ascii-art-image thing
 ///
-/// Image imports work by calling `Img::from_str(include_str!("path/to/file"))`
+/// Image imports work by calling `Img::parse(include_str!("path/to/file"))`
 /// The image files should be formatted with several likes of text that is the
 /// ascii art for the image, then on the first empty line, the program looks for
 /// colors, formatted as long lists of RRGGBB color hex codes which match up to
@@ -58,14 +58,13 @@ impl RenderableElement for Img {
 
 impl Img {
     /// Parse the text-image file format.
-    pub fn from_str(src: &str) -> Img {
+    pub fn parse(src: &str) -> Img {
         let mut text = vec![];
         let mut color = vec![];
         let src = src.trim_end();
 
         let mut colormode = false;
         for line in src.lines() {
-            let line = line;
             if line.is_empty() {
                 // Switch to capturing color on the first empty newline
                 colormode = true;
@@ -155,7 +154,7 @@ impl FromHex for Vec<u8> {
 
         for i in 0..slice.len() / 2 {
             let i = i * 2;
-            out.push((lut[&slice[i + 0]] << 4) + (lut[&slice[i + 1]]));
+            out.push((lut[&slice[i]] << 4) + (lut[&slice[i + 1]]));
         }
 
         out
@@ -5,17 +5,19 @@
  */
 
 use super::engine::{RenderResult, Screen, TextFrameBuffer};
+use crate::interface::bot::BotKind;
+use crate::player::Player;
 
 #[derive(Debug)]
 pub struct NameSetScreen<'a> {
-    names: &'a [String],
+    players: &'a [Player],
 }
 
 impl<'a> Screen for NameSetScreen<'a> {
     fn render_to_buffer(
         &self,
         fb: &mut TextFrameBuffer,
-        _: Option<&crate::game::Game>,
+        _: Option<&crate::view::PlayerView>,
     ) -> RenderResult<()> {
         for (i, ln) in [
             r#"____ _  _ ___ ____ ____    _  _ ____ _  _ ____ ____ "#,
@@ -29,29 +31,30 @@ impl<'a> Screen for NameSetScreen<'a> {
             fb.text(&ln[..fb.width().min(ln.len())], 0, i)?;
         }
 
-        if self.names.len() < 2 {
+        if self.players.len() < 2 {
             fb.text("Add at least 2 players...", 1, 5)?;
         }
 
-        for (i, name) in self.names.iter().enumerate() {
+        for (i, player) in self.players.iter().enumerate() {
             fb.text("- ", 0, i + 7)?;
-            fb.text_wrapped(name, 2, i + 7, fb.width() - 7)?;
+            fb.text_wrapped(player.name(), 2, i + 7, fb.width() - 7)?;
         }
 
         fb.set_input_prompt(
-            "Enter next player's name (max 50 chars), leave empty to begin game".to_string(),
+            "Enter next player's name (max 50 chars), or bot:easy / bot:hard / bot:expert to add a bot, leave empty to begin game".to_string(),
         );
 
         Ok(())
     }
 }
 
-pub fn select_names() -> RenderResult<Vec<String>> {
+/// Asks for the players' names until there are enough and an empty name is entered. Entering `bot:easy`, `bot:hard` or `bot:expert` adds a bot instead.
+pub fn select_names() -> RenderResult<Vec<Player>> {
     let mut players = vec![];
 
     loop {
         let new_name = (NameSetScreen {
-            names: &players[..],
+            players: &players[..],
         })
         .render_then_input(None)?
         .trim()
@@ -61,8 +64,11 @@ pub fn select_names() -> RenderResult<Vec<String>> {
             if players.len() >= 2 {
                 break;
             }
+        } else if let Ok(kind) = new_name.parse::<BotKind>() {
+            let name = format!("Bot {} ({})", players.len() + 1, kind.name());
+            players.push(Player::bot(name, kind));
         } else {
-            players.push(new_name);
+            players.push(Player::new(new_name));
         }
     }
 
@@ -4,14 +4,18 @@
  */
 
 use super::engine::{RenderResult, Screen, TextFrameBuffer};
-use crate::game::Game;
+use crate::view::PlayerView;
 
 #[derive(Debug)]
 pub struct PlayMoreConfirmScreen;
 
 impl Screen for PlayMoreConfirmScreen {
-    fn render_to_buffer(&self, fb: &mut TextFrameBuffer, _game: Option<&Game>) -> RenderResult<()> {
-        fb.set_input_prompt(format!("One more round (y/n)?"));
+    fn render_to_buffer(
+        &self,
+        fb: &mut TextFrameBuffer,
+        _view: Option<&PlayerView>,
+    ) -> RenderResult<()> {
+        fb.set_input_prompt("One more round (y/n)?".to_string());
         Ok(())
     }
 }
@@ -7,8 +7,11 @@ use super::ansi::ANSIColor;
 use super::engine::{RenderError, RenderResult, RenderableElement, Screen, TextFrameBuffer};
 use super::img::Img;
 use crate::card::{Card, RenderableCard};
-use crate::game::Game;
+use crate::interface::{MistakeReport, Notice};
+use crate::rule::{Rule, Side};
+use crate::view::PlayerView;
 use rand::seq::SliceRandom;
+use serde::{Deserialize, Serialize};
 
 /// The screen that represents any screen shown in play.
 #[derive(Debug)]
@@ -28,27 +31,42 @@ pub enum PlayScreen {
     /// Main turn screen, allows player to draw and play cards then speak.
     Turn(TurnState),
     /// Screen shown whenever the player violates a rule.
-    Mistake {
-        /// Things that should not have been said.
-        incs: Vec<String>,
-        /// ... in this round
-        current_incs: usize,
-        /// Things that should have been said.
-        fails: Vec<String>,
-        /// ... in this round
-        current_fails: usize,
-        /// Cards drawn as punishment.
-        drawn: Vec<Card>,
-        /// If you were given back your own card due to error.
-        card_error: bool,
-        /// ... in this round
-        current_card_error: bool,
-    },
+    Mistake(MistakeReport),
     /// Screen shown at the end of the game when there's a winner.
     Win {
         /// This is a player index.
         winner: usize,
+        /// Which of the end screens to show, any number is fine.
+        art: usize,
     },
+    /// Screen shown when the game couldn't be saved.
+    SaveFailed { path: String, issue: String },
+    /// Screen shown once the players stop playing, revealing every rule.
+    RulesRevealed(Vec<Rule>),
+}
+
+impl From<Notice> for PlayScreen {
+    /// The screen that shows the players `notice`.
+    fn from(notice: Notice) -> Self {
+        match notice {
+            Notice::NewRound { round_n } => Self::NewRound { round_n },
+            Notice::NewTurn => Self::NewTurn,
+            Notice::RuleInvocation(result) => Self::RuleInvocation(result),
+            Notice::RuleExists { winner } => Self::CreateRule {
+                winner,
+                state: CreateRuleState::RuleExists,
+                format_issue: None,
+            },
+            Notice::NoSuchRule { winner } => Self::CreateRule {
+                winner,
+                state: CreateRuleState::NoSuchRule,
+                format_issue: None,
+            },
+            Notice::Win { winner, art } => Self::Win { winner, art },
+            Notice::SaveFailed { path, issue } => Self::SaveFailed { path, issue },
+            Notice::RulesRevealed(rules) => Self::RulesRevealed(rules),
+        }
+    }
 }
 
 /// The substate of PlayScreen::Turn
@@ -60,28 +78,86 @@ pub enum TurnState {
     Speak(bool, Card),
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 pub enum CreateRuleState {
+    /// Choosing whether to add, remove or modify a rule.
+    ChooseChange,
     MakeEvent,
     MakeAction,
+    /// Describing the event of a rule to remove or modify.
+    DescribeEvent,
+    /// Describing the action of a rule to remove or modify.
+    DescribeAction,
     RuleExists,
+    /// The rule to remove or modify doesn't exist.
+    NoSuchRule,
+    /// Reordering the priority, after making the rule.
+    Reorder,
 }
-#[derive(Debug)]
+/// What a rule's action did. Cards are `None` if they're face down.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum RuleActionResult {
-    Draw(Card),
-    Skip { who: usize },
+    Draw {
+        who: usize,
+        cards: Vec<Option<Card>>,
+    },
+    Skip {
+        who: usize,
+    },
     Reverse,
-    Repeat { who: usize },
+    Repeat {
+        who: usize,
+    },
+    Pass {
+        from: usize,
+        to: usize,
+        side: Side,
+        card: Option<Card>,
+    },
+    SwapHands {
+        who: usize,
+        with: usize,
+    },
+}
+
+impl RuleActionResult {
+    /// Returns the result as `viewer` saw it, or as everybody did if it's `None`. Cards are only seen by the players they moved between.
+    pub fn seen_by(&self, viewer: Option<usize>) -> Self {
+        let hidden = |player: &usize| viewer != Some(*player);
+
+        match self {
+            Self::Draw { who, cards } if hidden(who) => Self::Draw {
+                who: *who,
+                cards: vec![None; cards.len()],
+            },
+            Self::Pass {
+                from,
+                to,
+                side,
+                card: Some(_),
+            } if hidden(from) && hidden(to) => Self::Pass {
+                from: *from,
+                to: *to,
+                side: *side,
+                card: None,
+            },
+            result => result.clone(),
+        }
+    }
 }
 
 impl Screen for PlayScreen {
-    fn render_to_buffer(&self, fb: &mut TextFrameBuffer, game: Option<&Game>) -> RenderResult<()> {
-        let game = game.expect("PlayScreen may only be used if the game exists!");
+    fn render_to_buffer(
+        &self,
+        fb: &mut TextFrameBuffer,
+        view: Option<&PlayerView>,
+    ) -> RenderResult<()> {
+        let view = view.expect("PlayScreen may only be used if the game exists!");
 
         match self {
             PlayScreen::NewRound { round_n } => {
                 // Draw the big text graphic.
-                let img = Img::from_str(include_str!("images/next_round"));
+                let img = Img::parse(include_str!("images/next_round"));
                 img.render(fb, fb.width().saturating_sub(img.max_width()) / 2, 1)?;
 
                 let round_text = format!("ROUND #{round_n}");
@@ -93,10 +169,10 @@ impl Screen for PlayScreen {
             }
             PlayScreen::NewTurn => {
                 // Draw the big text and arrow graphic.
-                Img::from_str(include_str!("images/next_player")).render(fb, 0, 0)?;
+                Img::parse(include_str!("images/next_player")).render(fb, 0, 0)?;
 
                 // Text that says who's turn it is.
-                let player = game.current_player().name();
+                let player = view.current_player().name();
                 fb.text_wrapped(
                     format!("Your turn, {player}! Make sure nobody else is watching your hand!")
                         .as_str(),
@@ -105,22 +181,21 @@ impl Screen for PlayScreen {
                     fb.width(),
                 )?;
             }
-            PlayScreen::Win { winner } => {
+            PlayScreen::Win { winner, art } => {
                 // The "game over" ascii art text.
-                Img::from_str(include_str!("images/game_over_text")).render(fb, 0, 0)?;
+                Img::parse(include_str!("images/game_over_text")).render(fb, 0, 0)?;
 
-                // TODO: select one of many end screens
-                let (center, img_src) = [
+                // Select one of many end screens
+                let end_screens = [
                     (true, include_str!("images/game_over/brandon")),
                     (true, include_str!("images/game_over/lookinside")),
                     (false, include_str!("images/game_over/stand")),
                     (true, include_str!("images/game_over/doge")),
                     (true, include_str!("images/game_over/rust")),
                     (true, include_str!("images/game_over/bobross")),
-                ][..]
-                    .choose(&mut rand::thread_rng())
-                    .unwrap();
-                let img = Img::from_str(img_src);
+                ];
+                let (center, img_src) = &end_screens[*art % end_screens.len()];
+                let img = Img::parse(img_src);
                 img.render(
                     fb,
                     if *center {
@@ -132,7 +207,7 @@ impl Screen for PlayScreen {
                 )?;
 
                 // Text that says who won.
-                let winner = game.players()[*winner].name();
+                let winner = view.players()[*winner].name();
                 fb.text_wrapped(
                     format!("{winner} wins or something").as_str(),
                     0,
@@ -140,19 +215,60 @@ impl Screen for PlayScreen {
                     fb.width(),
                 )?;
             }
+            PlayScreen::RulesRevealed(rules) => {
+                fb.text_wrapped("The secret rules were...", 0, 0, fb.width())?;
+
+                // Each rule takes two lines, cut short so that as many fit as possible.
+                let shown = fb.height().saturating_sub(2) / 2;
+                let width = fb.width();
+                let line = |text: String| text.chars().take(width).collect::<String>();
+                for (i, rule) in rules.iter().take(shown).enumerate() {
+                    let y = 2 + i * 2;
+                    fb.text_wrapped(line(format!("║ {}", rule)).as_str(), 0, y, fb.width())?;
+
+                    let author = match rule.author() {
+                        Some(author) => format!(
+                            "made by {} in round {}",
+                            view.players()[author.player].name(),
+                            author.round
+                        ),
+                        None => "there from the start".to_string(),
+                    };
+                    fb.text_wrapped(
+                        line(format!(
+                            "║   {author}, caused {} penalties",
+                            rule.penalties()
+                        ))
+                        .as_str(),
+                        0,
+                        y + 1,
+                        fb.width(),
+                    )?;
+                    fb.style_fg_box(ANSIColor::LightBlack, 0, y + 1, fb.width(), 1)?;
+                }
+            }
+            PlayScreen::SaveFailed { path, issue } => {
+                fb.text_wrapped(
+                    format!("Failed to save the game to '{path}': {issue}").as_str(),
+                    0,
+                    0,
+                    fb.width(),
+                )?;
+                fb.style_fg_box(ANSIColor::Red, 0, 0, fb.width(), 2)?;
+            }
             PlayScreen::CreateRule {
                 winner,
                 state,
                 format_issue,
             } => {
                 // Draw the big text graphic.
-                let img = Img::from_str(include_str!("images/new_rule_text"));
+                let img = Img::parse(include_str!("images/new_rule_text"));
                 img.render(fb, fb.width().saturating_sub(img.max_width()) / 2, 1)?;
 
                 // Text that says who's turn it is.
-                let player = game.players()[*winner].name();
+                let player = view.players()[*winner].name();
                 fb.text_wrapped(
-                    format!("Winner \"{player}\" makes new rule! Add a new rule to the game, and make sure nobody's watching!")
+                    format!("Winner \"{player}\" changes the rules! Add, remove or change a rule, and make sure nobody's watching!")
                         .as_str(),
                     0,
                     5,
@@ -165,67 +281,209 @@ impl Screen for PlayScreen {
                 }
 
                 match state {
-                    CreateRuleState::MakeEvent => {
+                    CreateRuleState::ChooseChange => {
                         fb.text_wrapped(
-                            "Select the condition the rule activates on...",
+                            "Add a new rule, or remove or modify an existing one?",
                             0,
                             7,
                             fb.width(),
                         )?;
+                        fb.text_wrapped("║ add", 0, 9, fb.width())?;
+                        fb.text_wrapped("║ remove", 0, 10, fb.width())?;
+                        fb.text_wrapped("║ modify", 0, 11, fb.width())?;
+                        fb.text_wrapped(
+                            "[note: rules are removed or modified by describing their exact condition and action. Leave it empty to add a rule]",
+                            0,
+                            12,
+                            fb.width(),
+                        )?;
+                        fb.style_fg_box(ANSIColor::LightBlack, 0, 12, fb.width(), 2)?;
+
+                        let own_rules = view.own_rules();
+                        if !own_rules.is_empty() {
+                            fb.text_wrapped("Your rules:", 0, 15, fb.width())?;
+                            let shown = fb.height().saturating_sub(16);
+                            for (i, rule) in own_rules.iter().take(shown).enumerate() {
+                                let text = format!("║ {}", rule);
+                                fb.text_wrapped(
+                                    text.chars().take(fb.width()).collect::<String>().as_str(),
+                                    0,
+                                    16 + i,
+                                    fb.width(),
+                                )?;
+                            }
+                        }
+                        fb.set_input_prompt("Enter add, remove or modify:".to_string());
+                    }
+                    CreateRuleState::MakeEvent | CreateRuleState::DescribeEvent => {
+                        let heading = match state {
+                            CreateRuleState::MakeEvent => {
+                                "Select the condition the rule activates on..."
+                            }
+                            _ => "Describe the condition of the rule to change...",
+                        };
+                        fb.text_wrapped(heading, 0, 7, fb.width())?;
                         fb.text_wrapped("Format:", 0, 9, fb.width())?;
                         fb.text_wrapped("║ suit is <suit>", 0, 10, fb.width())?;
                         fb.text_wrapped("║ value is <value>", 0, 11, fb.width())?;
                         fb.text_wrapped("║ card is <value> of <suit>", 0, 12, fb.width())?;
-                        fb.text_wrapped("Example: \"card is 4 of spades\"", 0, 14, fb.width())?;
-                        fb.set_input_prompt(format!("Enter rule event:"));
+                        fb.text_wrapped("║ red / black / face card", 0, 13, fb.width())?;
+                        fb.text_wrapped(
+                            "║ same value / same suit / colour changed / run of <n>",
+                            0,
+                            14,
+                            fb.width(),
+                        )?;
+                        fb.text_wrapped("║ <condition> <n> in a row", 0, 15, fb.width())?;
+                        fb.text_wrapped(
+                            "║ draw / penalty / reversed / round start / <n> cards left",
+                            0,
+                            16,
+                            fb.width(),
+                        )?;
+                        fb.text_wrapped(
+                            "[note: conditions can be combined with \"and\", \"or\" and \"not\", and grouped with brackets]",
+                            0,
+                            17,
+                            fb.width(),
+                        )?;
+                        fb.style_fg_box(ANSIColor::LightBlack, 0, 17, fb.width(), 2)?;
+                        fb.text_wrapped("Example: \"card is 4 of spades\"", 0, 20, fb.width())?;
+                        fb.text_wrapped("Example: \"red and face card\"", 0, 21, fb.width())?;
+                        fb.text_wrapped("Example: \"hearts twice in a row\"", 0, 22, fb.width())?;
+                        fb.text_wrapped(
+                            "Example: on value 7 say \"have a nice day\" (a whole rule at once)",
+                            0,
+                            23,
+                            fb.width(),
+                        )?;
+                        fb.set_input_prompt("Enter rule event:".to_string());
                     }
-                    CreateRuleState::MakeAction => {
-                        fb.text_wrapped("Select the action the rule takes...", 0, 7, fb.width())?;
+                    CreateRuleState::MakeAction | CreateRuleState::DescribeAction => {
+                        let heading = match state {
+                            CreateRuleState::MakeAction => "Select the action the rule takes...",
+                            _ => "Describe the action of the rule to change...",
+                        };
+                        fb.text_wrapped(heading, 0, 7, fb.width())?;
                         fb.text_wrapped("Format:", 0, 9, fb.width())?;
 
-                        fb.text_wrapped("║ draw", 0, 10, fb.width())?;
-                        fb.text_wrapped("║ repeat", 0, 11, fb.width())?;
-                        fb.text_wrapped("║ reverse", 0, 12, fb.width())?;
-                        fb.text_wrapped("║ skip", 0, 13, fb.width())?;
-                        fb.text_wrapped("║ say <text>", 0, 14, fb.width())?;
-                        fb.text_wrapped("[note: \"{value}\" \"{card}\" and \"{suit}\" in <text> will be replaced to match the played card. ex. \"hello {value}\" -> \"hello four\"]", 0, 15, fb.width())?;
-                        fb.style_fg_box(ANSIColor::LightBlack, 0, 15, fb.width(), 2)?;
-
-                        fb.text_wrapped("Example: \"draw\"", 0, 18, fb.width())?;
-                        fb.text_wrapped("Example: \"say hello world\"", 0, 19, fb.width())?;
-                        fb.text_wrapped("Example: \"say I played {card}\"", 0, 20, fb.width())?;
+                        fb.text_wrapped("║ draw <n>", 0, 10, fb.width())?;
+                        fb.text_wrapped("║ next draws <n>", 0, 11, fb.width())?;
+                        fb.text_wrapped("║ pass left / pass right", 0, 12, fb.width())?;
+                        fb.text_wrapped("║ swap hands", 0, 13, fb.width())?;
+                        fb.text_wrapped("║ repeat", 0, 14, fb.width())?;
+                        fb.text_wrapped("║ reverse", 0, 15, fb.width())?;
+                        fb.text_wrapped("║ skip <n>", 0, 16, fb.width())?;
+                        fb.text_wrapped("║ say <text>", 0, 17, fb.width())?;
+                        fb.text_wrapped("║ allow / forbid (playing the card)", 0, 18, fb.width())?;
+                        fb.text_wrapped("[note: <n> can be left out for 1. \"{value}\" \"{card}\" and \"{suit}\" in <text> will be replaced to match the played card. ex. \"hello {value}\" -> \"hello four\"]", 0, 19, fb.width())?;
+                        fb.style_fg_box(ANSIColor::LightBlack, 0, 19, fb.width(), 2)?;
+
+                        fb.text_wrapped("Example: \"draw 2\"", 0, 22, fb.width())?;
+                        fb.text_wrapped("Example: \"say hello world\"", 0, 23, fb.width())?;
+                        fb.text_wrapped(
+                            "Example: \"forbid\" (for \"jack twice in a row\")",
+                            0,
+                            24,
+                            fb.width(),
+                        )?;
 
-                        fb.set_input_prompt(format!("Enter rule action:"));
+                        fb.set_input_prompt("Enter rule action:".to_string());
                     }
                     CreateRuleState::RuleExists => {
                         fb.style_fg_box(ANSIColor::Red, 0, 7, fb.width(), 2)?;
                         fb.text_wrapped(
-                            "An identical rule already exists, try again.",
+                            "An identical or overlapping rule already exists, try again.",
                             0,
                             7,
                             fb.width(),
                         )?;
                     }
+                    CreateRuleState::NoSuchRule => {
+                        fb.style_fg_box(ANSIColor::Red, 0, 7, fb.width(), 2)?;
+                        fb.text_wrapped("There's no rule like that, try again.", 0, 7, fb.width())?;
+                    }
+                    CreateRuleState::Reorder => {
+                        fb.text_wrapped(
+                            "Change the order rules are applied in? Their actions happen in this order:",
+                            0,
+                            7,
+                            fb.width(),
+                        )?;
+                        fb.text_wrapped(
+                            format!("║ {}", view.priority()).as_str(),
+                            0,
+                            9,
+                            fb.width(),
+                        )?;
+                        fb.text_wrapped(
+                            "[note: the actions listed are moved to the front, in that order, and the rest keep their order after them. Leave it empty to keep the order]",
+                            0,
+                            12,
+                            fb.width(),
+                        )?;
+                        fb.style_fg_box(ANSIColor::LightBlack, 0, 12, fb.width(), 2)?;
+                        fb.text_wrapped("Example: \"skip, reverse\"", 0, 15, fb.width())?;
+                        fb.set_input_prompt("Enter actions to go first:".to_string());
+                    }
                 };
             }
             PlayScreen::RuleInvocation(action) => {
                 // Draw the big text graphic.
-                let img = Img::from_str(include_str!("images/rule_invoked_text"));
+                let img = Img::parse(include_str!("images/rule_invoked_text"));
                 img.render(fb, fb.width().saturating_sub(img.max_width()) / 2, 1)?;
 
                 match action {
-                    RuleActionResult::Draw(card) => {
+                    RuleActionResult::Draw { who, cards } => {
+                        let text = match cards.len() {
+                            1 => format!("{} draws a card", view.players()[*who].name()),
+                            n => format!("{} draws {n} cards", view.players()[*who].name()),
+                        };
+                        fb.text_wrapped(text.as_str(), 2, 5, fb.width() - 4)?;
+
+                        // As many as fit across the screen.
+                        let max_cards = (fb.width() - 2) / (RenderableCard::W + 1);
+                        for (i, card) in cards.iter().take(max_cards).enumerate() {
+                            let x = 2 + i * (RenderableCard::W + 1);
+                            match card {
+                                Some(card) => RenderableCard::Front(*card).render(fb, x, 7)?,
+                                None => RenderableCard::Back.render(fb, x, 7)?,
+                            }
+                        }
+                    }
+                    RuleActionResult::Pass { from, to, card, .. } => {
                         fb.text_wrapped(
-                            format!("{} draws a card", game.current_player().name()).as_str(),
+                            format!(
+                                "{} passes a card to {}",
+                                view.players()[*from].name(),
+                                view.players()[*to].name()
+                            )
+                            .as_str(),
+                            2,
+                            5,
+                            fb.width() - 4,
+                        )?;
+                        match card {
+                            Some(card) => RenderableCard::Front(*card).render(fb, 2, 7)?,
+                            None => RenderableCard::Back.render(fb, 2, 7)?,
+                        }
+                    }
+                    RuleActionResult::SwapHands { who, with } => {
+                        fb.text_wrapped(
+                            format!(
+                                "{} and {} swap hands",
+                                view.players()[*who].name(),
+                                view.players()[*with].name()
+                            )
+                            .as_str(),
                             2,
                             5,
                             fb.width() - 4,
                         )?;
-                        RenderableCard::Front(*card).render(fb, 2, 7)?;
                     }
                     RuleActionResult::Repeat { who } => {
                         fb.text_wrapped(
-                            format!("{} gets an extra turn", game.players()[*who].name()).as_str(),
+                            format!("{} gets an extra turn", view.players()[*who].name()).as_str(),
                             2,
                             5,
                             fb.width() - 4,
@@ -236,24 +494,35 @@ impl Screen for PlayScreen {
                     }
                     RuleActionResult::Skip { who } => {
                         fb.text_wrapped(
-                            format!("{} has been skipped", game.players()[*who].name()).as_str(),
+                            format!("{} has been skipped", view.players()[*who].name()).as_str(),
                             2,
                             5,
                             fb.width() - 4,
                         )?;
                     }
                 }
+
+                // The order the rules' actions were applied in.
+                let y = fb.height().saturating_sub(3);
+                fb.text_wrapped(
+                    format!("Applied in the order: {}", view.priority()).as_str(),
+                    2,
+                    y,
+                    fb.width() - 4,
+                )?;
+                fb.style_fg_box(ANSIColor::LightBlack, 2, y, fb.width() - 4, 2)?;
             }
             PlayScreen::Turn(state) => {
                 // Nice little background graphic in the corner.
-                Img::from_str(include_str!("images/bg")).render(
+                Img::parse(include_str!("images/bg")).render(
                     fb,
                     fb.width() - 64 / 2,
                     fb.height() - 64 / 4,
                 )?;
 
                 // Remind plyers who's turn it is.
-                let player = game.current_player();
+                let player = view.current_player();
+                let hand = view.hand();
                 fb.text_wrapped(
                     format!("Your turn, {}", player.name()).as_str(),
                     0,
@@ -266,14 +535,14 @@ impl Screen for PlayScreen {
                 const DX: usize = 4;
                 let n_hand_cols = (fb.width() - 13) / DX;
 
-                for (i, card) in player.hand().iter().enumerate() {
+                for (i, card) in hand.iter().enumerate() {
                     let rend = RenderableCard::Front(*card);
                     let ix = i % n_hand_cols;
                     let iy = i / n_hand_cols;
 
                     if iy >= 3 {
                         // no more than three rows
-                        let text = format!("... total of {} cards", player.hand().len());
+                        let text = format!("... total of {} cards", hand.len());
                         let y = (RenderableCard::H + 1) * 3 + 5;
                         fb.text(
                             text.as_str(),
@@ -306,17 +575,15 @@ impl Screen for PlayScreen {
                 // Show the current top of the played deck.
                 fb.text("Top", 3, 3 + RenderableCard::H * 2 + 2)?;
                 RenderableCard::Back.render(fb, 2, 4 + RenderableCard::H * 2 + 2)?;
-                RenderableCard::Front(game.used_pile().cards()[0]).render(
-                    fb,
-                    3,
-                    4 + RenderableCard::H * 2 + 2,
-                )?;
+                if let Some(top) = view.top_card() {
+                    RenderableCard::Front(top).render(fb, 3, 4 + RenderableCard::H * 2 + 2)?;
+                }
 
                 match state {
                     // Action turns, player should draw or play.
                     TurnState::Action => fb.set_input_prompt(format!(
-                        "D → Draw From Deck, 1-{} → Play Card",
-                        player.hand().len()
+                        "D → Draw From Deck, 1-{} → Play Card, Save <file> → Save Game",
+                        hand.len()
                     )),
                     // Speak turn, player is prompted to speak.
                     TurnState::Speak(did_draw, card) => {
@@ -336,18 +603,18 @@ impl Screen for PlayScreen {
                         // Update the top of the deck if the card was played to show what
                         // would be visible before playing the card (in the code the play
                         // has already happened)
-                        if !did_draw {
-                            RenderableCard::Front(game.used_pile().cards()[1]).render(
+                        if let (false, Some(card)) = (did_draw, view.under_top_card()) {
+                            RenderableCard::Front(card).render(
                                 fb,
                                 3,
                                 4 + RenderableCard::H * 2 + 2,
                             )?;
                         }
-                        fb.set_input_prompt(format!("Anything to say? (leave blank for silent)"))
+                        fb.set_input_prompt("Anything to say? (leave blank for silent)".to_string())
                     }
                 }
             }
-            PlayScreen::Mistake {
+            PlayScreen::Mistake(MistakeReport {
                 incs,
                 current_incs,
                 fails,
@@ -355,9 +622,9 @@ impl Screen for PlayScreen {
                 drawn,
                 card_error,
                 current_card_error,
-            } => {
+            }) => {
                 // Say who is the one who made the mistake.
-                let name = game.current_player().name();
+                let name = view.current_player().name();
                 fb.text_wrapped(
                     format!(" :: {name} made a mistake! ::").as_str(),
                     0,
@@ -397,7 +664,7 @@ impl Screen for PlayScreen {
                 // Draw all "card error" lines.
                 if *card_error {
                     fb.text_wrapped(
-                        format!("- Incorrect play").as_str(),
+                        "- Incorrect play",
                         half_width,
                         1,
                         half_width - 1,
@@ -412,7 +679,7 @@ impl Screen for PlayScreen {
                 }
 
                 // Draw all "incorrect usage" lines
-                for (i, inc) in incs.into_iter().enumerate() {
+                for (i, inc) in incs.iter().enumerate() {
                     fb.text_wrapped(
                         format!("- Incorrect use of \"{}\".", inc).as_str(),
                         0,
@@ -427,7 +694,7 @@ impl Screen for PlayScreen {
                 }
 
                 // Draw all "failed to say" lines
-                for (i, fail) in fails.into_iter().enumerate() {
+                for (i, fail) in fails.iter().enumerate() {
                     fb.text_wrapped(
                         format!("- Failure to say \"{}\".", fail).as_str(),
                         half_width,
@@ -0,0 +1,61 @@
+/*
+ * This file is responsible for the replay viewer, which steps
+ * through the frames of a replayed game one at a time.
+ */
+
+use super::engine::{RenderResult, Screen, TextFrameBuffer};
+use crate::interface::replay::ReplayFrame;
+use crate::view::PlayerView;
+
+/// Screen showing one frame of a replay, along with what was entered on it.
+#[derive(Debug)]
+pub struct ReplayScreen<'a> {
+    frame: &'a ReplayFrame,
+    step: usize,
+    steps: usize,
+}
+
+impl<'a> Screen for ReplayScreen<'a> {
+    fn render_to_buffer(
+        &self,
+        fb: &mut TextFrameBuffer,
+        _view: Option<&PlayerView>,
+    ) -> RenderResult<()> {
+        *fb = self.frame.frame.clone();
+
+        let input = match &self.frame.input {
+            Some(input) => format!("{input} | "),
+            None => "".to_string(),
+        };
+        fb.set_input_prompt(format!(
+            "[{}/{}] {input}N → Next, P → Previous, Q → Quit",
+            self.step + 1,
+            self.steps
+        ));
+
+        Ok(())
+    }
+}
+
+/// Shows the frames of a replay one by one, letting the user go back and forth between them until they quit.
+pub fn view_replay(frames: &[ReplayFrame]) -> RenderResult<()> {
+    let mut step = 0;
+
+    while let Some(frame) = frames.get(step) {
+        let res = (ReplayScreen {
+            frame,
+            step,
+            steps: frames.len(),
+        })
+        .render_then_input(None)?;
+
+        match res.trim().to_lowercase().as_str() {
+            "" | "n" | "next" => step = (step + 1).min(frames.len() - 1),
+            "p" | "prev" | "previous" => step = step.saturating_sub(1),
+            "q" | "quit" => break,
+            _ => continue,
+        }
+    }
+
+    Ok(())
+}
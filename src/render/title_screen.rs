@@ -5,11 +5,11 @@
  */
 
 use super::engine::{RenderResult, Screen, TextFrameBuffer};
-use crate::game::Game;
 use rand::seq::SliceRandom;
 
 use super::engine::RenderableElement;
 use super::img::Img;
+use crate::view::PlayerView;
 
 #[derive(Debug)]
 pub struct TitleScreen {
@@ -17,12 +17,16 @@ pub struct TitleScreen {
 }
 
 impl Screen for TitleScreen {
-    fn render_to_buffer(&self, fb: &mut TextFrameBuffer, _game: Option<&Game>) -> RenderResult<()> {
-        Img::from_str(include_str!("images/title_screen")).render(fb, 0, 0)?;
+    fn render_to_buffer(
+        &self,
+        fb: &mut TextFrameBuffer,
+        _view: Option<&PlayerView>,
+    ) -> RenderResult<()> {
+        Img::parse(include_str!("images/title_screen")).render(fb, 0, 0)?;
         fb.text_wrapped(splash(), 5, 5, fb.width() - 10)?;
 
         if self.show_instructions {
-          let img = Img::from_str(include_str!("images/instructions_screen"));
+          let img = Img::parse(include_str!("images/instructions_screen"));
           img.render(
             fb,
             fb.width().saturating_sub(img.max_width())/2, 
@@ -35,7 +39,7 @@ impl Screen for TitleScreen {
 }
 
 fn splash() -> &'static str {
-    &[
+    [
         "The only card game about being confused.",
         "Wikipedia says this game is about Mao Zedong's rule being chaotic.",
         "Gluten free",
@@ -0,0 +1,130 @@
+/*
+ * A "Replay" is everything needed to play a game over again
+ * exactly as it happened: the seed, the players' names and
+ * every decision that was made, in order. Replays are saved
+ * as JSON, and can be stepped through with `mao replay <file>`.
+ */
+
+use crate::game::config::GameConfig;
+use crate::game::Game;
+use crate::interface::Move;
+use crate::player::Player;
+use crate::rule::priority::Priority;
+use crate::rule::{Rule, RuleChange};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::fs::File;
+use std::io::{BufReader, BufWriter};
+use std::path::Path;
+
+/// A single decision given to the game, in answer to one of the `PlayerInterface` requests.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Input {
+    /// The answer to `request_move`.
+    Move(Move),
+    /// The answer to `request_speech` or `request_penalty_speech`.
+    Speech(Vec<String>),
+    /// The answer to `confirm_next_round`.
+    NextRound(bool),
+    /// The answer to `request_rule`.
+    RuleChange(RuleChange),
+    /// The answer to `request_priority`.
+    Priority(Option<Priority>),
+}
+
+/// A recording of a whole game, that can be played back.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Replay {
+    pub seed: u64,
+    pub players: Vec<String>,
+    pub inputs: Vec<Input>,
+    /// The priority the game started with.
+    #[serde(default)]
+    pub priority: Priority,
+    /// The rules the game started with, or `None` for the default ones.
+    #[serde(default)]
+    pub rules: Option<Vec<Rule>>,
+    /// The settings the game was set up with.
+    #[serde(default)]
+    pub config: GameConfig,
+}
+
+impl Replay {
+    /// Creates a new `Replay` of a game which was started with `seed` and `players`.
+    pub fn new(seed: u64, players: Vec<String>, inputs: Vec<Input>) -> Self {
+        Self {
+            seed,
+            players,
+            inputs,
+            priority: Priority::default(),
+            rules: None,
+            config: GameConfig::default(),
+        }
+    }
+
+    /// Sets the priority the game started with, for a game that wasn't using the default one.
+    pub fn with_priority(mut self, priority: Priority) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Sets the rules the game started with, for a game that wasn't using the default ones (eg. from a rules file).
+    pub fn with_rules(mut self, rules: Vec<Rule>) -> Self {
+        self.rules = Some(rules);
+        self
+    }
+
+    /// Sets the settings the game was set up with, for a game that wasn't the usual game of Mao.
+    pub fn with_config(mut self, config: GameConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Creates the game as it was before any of the inputs were given.
+    pub fn new_game(&self) -> Game {
+        let mut game = Game::with_config(
+            self.players.iter().cloned().map(Player::new).collect(),
+            self.seed,
+            self.config.clone(),
+        );
+        // Like the rules, the priority can only be wrong if the replay was edited.
+        let _ = game.set_priority(self.priority.clone());
+        if let Some(rules) = &self.rules {
+            // The rules were all in a game together, so they can't conflict unless the replay was edited, in which case it won't play back the same anyway.
+            let _ = game.use_rules(rules.clone());
+        }
+        game
+    }
+
+    /// Saves the replay as JSON to the file at `path`.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> std::io::Result<()> {
+        serde_json::to_writer(BufWriter::new(File::create(path)?), self)?;
+        Ok(())
+    }
+
+    /// Loads a replay that was saved with `Replay::save` from the file at `path`.
+    pub fn load<P: AsRef<Path>>(path: P) -> std::io::Result<Self> {
+        Ok(serde_json::from_reader(BufReader::new(File::open(path)?))?)
+    }
+}
+
+impl Display for Input {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Move(Move::Draw) => write!(f, "Drew a card"),
+            Self::Move(Move::Play(i)) => write!(f, "Played card {}", i + 1),
+            Self::Move(Move::Save(path)) => write!(f, "Saved the game to {path}"),
+            Self::Speech(quotes) if quotes.is_empty() => write!(f, "Said nothing"),
+            Self::Speech(quotes) => write!(f, "Said \"{}\"", quotes.join(". ")),
+            Self::NextRound(true) => write!(f, "Played another round"),
+            Self::NextRound(false) => write!(f, "Stopped playing"),
+            Self::RuleChange(RuleChange::Add(rule)) => write!(f, "Made the rule '{rule}'"),
+            Self::RuleChange(RuleChange::Remove(rule)) => write!(f, "Removed the rule '{rule}'"),
+            Self::RuleChange(RuleChange::Modify { old, new }) => {
+                write!(f, "Changed the rule '{old}' to '{new}'")
+            }
+            Self::Priority(None) => write!(f, "Kept the priority"),
+            Self::Priority(Some(priority)) => write!(f, "Reordered the priority to {priority}"),
+        }
+    }
+}
@@ -6,27 +6,110 @@
  */
 
 use crate::card::{Card, Suit, Value};
+use crate::rule::priority::ActionValue;
+use enum_iterator::{all, Sequence};
+use serde::{Deserialize, Serialize};
 use std::cmp::{Eq, PartialEq};
-use std::hash::Hash;
+use std::fmt::Display;
+use std::hash::{Hash, Hasher};
+use tinytemplate::TinyTemplate;
 
+/// The most times an action can happen, eg. "draw 10". Any more and drawing could take deck after deck, or skipping go round the table for ever.
+pub const MAX_COUNT: u32 = 10;
+
+/// The most events about the cards played before or the player's hand (eg. `same suit` or `3 cards left`) that an event can have. Every combination of them happening or not is tried to check the event against others (see `Event::overlaps`), so the checks take twice as long for each one.
+pub const MAX_CONTEXT_EVENTS: usize = 4;
+
+pub mod dsl;
+pub mod inference;
+pub mod legality;
 pub mod priority;
 pub mod rule_map;
 
 /// A struct representing an in-game rule
 ///
 /// The structure is: `On EVENT do ACTION`.
-#[derive(Debug, Clone, Hash)]
+///
+/// Rules are equal if they have the same event and action, no matter who made them.
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Rule {
     event: Event,
     action: Action,
+    #[serde(default)]
+    author: Option<Author>, // `None` for rules the game started with
+    #[serde(default)]
+    penalties: usize, // Penalties the rule has caused
+}
+
+/// Who made a rule, and in which round.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Author {
+    pub player: usize,
+    pub round: usize,
 }
 
 /// A enum of the possible events that can trigger a rule action or requirement.
-#[derive(Debug, Clone, PartialEq, Hash)]
+///
+/// Events can be combined with `And`, `Or` and `Not`, eg. "red and face card". Some events depend on the cards played before the last one, so they need the whole used pile to be matched (see `Event::matches`).
+///
+/// Jokers are matched by their value (`Value::Joker`, eg. "value joker" or just "joker") and their colour, but never by a suit.
+///
+/// Every event is set off by one or more `Trigger`s. Most are about a card being played, but `CardDrawn`, `Penalized`, `OrderReversed` and `RoundStarted` aren't, so eg. "not 7" only happens when a card is played.
+#[derive(Debug, Clone, PartialEq, Hash, Serialize, Deserialize)]
 pub enum Event {
     CardPlayed(Card),
     ValuePlayed(Value),
     SuitPlayed(Suit),
+    /// A hearts or diamonds card was played.
+    RedPlayed,
+    /// A clubs or spades card was played.
+    BlackPlayed,
+    /// A jack, queen or king was played.
+    FacePlayed,
+    /// The card played has the same value as the card before it.
+    SameValueAsPrevious,
+    /// The card played has the same suit as the card before it.
+    SameSuitAsPrevious,
+    /// The last `usize` cards played each matched the event, eg. two 7s in a row.
+    InARow(Box<Event>, usize),
+    /// The values of the last `usize` cards played go up or down one at a time, eg. 4, 5, 6.
+    Run(usize),
+    /// The card played is a different colour to the card before it.
+    ColorChanged,
+    /// The player has `usize` cards left after playing a card.
+    CardsLeft(usize),
+    /// The player drew a card on their turn.
+    CardDrawn,
+    /// The player was penalized.
+    Penalized,
+    /// The order of play was reversed by a rule.
+    OrderReversed,
+    /// It's the first turn of a round.
+    RoundStarted,
+    And(Box<Event>, Box<Event>),
+    Or(Box<Event>, Box<Event>),
+    Not(Box<Event>),
+}
+
+/// The things that can happen in a game which set off rules.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Sequence)]
+pub enum Trigger {
+    CardPlayed,
+    CardDrawn,
+    Penalty,
+    /// The order of play was reversed. Rules set off by this are applied along with the rules that reversed it.
+    Reversed,
+    RoundStarted,
+}
+
+/// What an event is checked against: what just happened, and what the game is like now.
+#[derive(Debug, Clone, Copy)]
+pub struct Situation<'a> {
+    pub trigger: Trigger,
+    /// The used pile, with the newest card first.
+    pub played: &'a [Card],
+    /// The number of cards left in the hand of the player it happened to.
+    pub cards_left: usize,
 }
 
 /// An enum of the actions that can happen when a rule is triggered.
@@ -34,19 +117,98 @@ pub enum Event {
 /// `Action::Say` is special, because it is a _requirement_, rather than an _action_. So nothing happens when a `Say` action occurs. Rather, it adds a requirement that the player say something.
 ///
 /// The order of action execution is the order of the variants.
-#[derive(Debug, Clone, PartialEq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Action {
     Say(String), // Perhaps have a macro that inserts the string into println!, so they can put {card}, {suit}, and {value} in the string to interpolate?. Also, this variant is the only variant which doesn't actually do something, but checks if the player does it correctly.
-    Draw,        // Number of cards to draw
+    Draw(u32),   // Number of cards to draw
+    DrawNext(u32), // Number of cards the next player draws
+    /// The player passes a random card from their hand to the player on that side of them.
+    Pass(Side),
+    /// The player swaps hands with the next player.
+    SwapHands,
     Repeat,
     Reverse,
-    Skip,
+    Skip(u32), // Number of players to skip
+    /// The card can be played, even if the play policy doesn't allow it. Legality rules are only matched against cards being played (see `RuleMap::allows`).
+    Allow,
+    /// The card can't be played, even if the play policy or an `Allow` rule allows it.
+    Forbid,
+}
+
+/// A change the winner of a round makes to the rules. Rules to remove or modify are described by their event and action, so nobody has to be shown the rules to pick one.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum RuleChange {
+    Add(Rule),
+    /// Removes the rule with the same event and action.
+    Remove(Rule),
+    /// Replaces the rule with the same event and action as `old` with `new`.
+    Modify {
+        old: Rule,
+        new: Rule,
+    },
+}
+
+/// A side of a player at the table. The player on the left is the next one in seat order, and the one on the right is the one before, whichever way play is going.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Side {
+    Left,
+    Right,
 }
 
 impl Rule {
     /// Creates a new `Rule` from the `event` and `action`.
     pub fn new(event: Event, action: Action) -> Self {
-        Self { event, action }
+        Self {
+            event,
+            action,
+            author: None,
+            penalties: 0,
+        }
+    }
+
+    /// Returns the rule, marked as made by `player` after winning round `round`.
+    pub fn made_by(self, player: usize, round: usize) -> Self {
+        Self {
+            author: Some(Author { player, round }),
+            ..self
+        }
+    }
+
+    /// Parses a rule written as `on <event> <action>`, eg. `on value 7 say "have a nice day"`, with the event and action in the same formats as `Event::parse` and `Action::parse`. This is how rules are written in rules files (see `rule::dsl`).
+    ///
+    /// Returns `Err` with a description of the problem if `str` isn't a valid rule.
+    pub fn parse(str: &str) -> Result<Self, String> {
+        let str = str.trim();
+        let rule = match str.get(..3) {
+            Some(on) if on.eq_ignore_ascii_case("on ") => &str[3..],
+            _ => return Err(format!("'{}' has to start with 'on'", str)),
+        };
+
+        // The action could start at any word not in quotes, so each one is tried, from the last one back, until the words before it make an event.
+        let mut in_quotes = false;
+        let mut starts = vec![];
+        for (i, c) in rule.char_indices() {
+            if c == '"' {
+                in_quotes = !in_quotes;
+            } else if !in_quotes && i > 0 && c != ' ' && rule[..i].ends_with(' ') {
+                starts.push(i);
+            }
+        }
+
+        let mut issue = None;
+        for &i in starts.iter().rev() {
+            let Ok(action) = Action::parse(&rule[i..]) else {
+                continue;
+            };
+            match Event::parse(&rule[..i]) {
+                Ok(event) => return Ok(Self::new(event, action)),
+                Err(event_issue) => {
+                    issue.get_or_insert(event_issue);
+                }
+            }
+        }
+
+        Err(issue.unwrap_or_else(|| format!("'{}' has no action", str)))
     }
 
     /// Returns the `Event` which the rule will trigger on.
@@ -58,6 +220,72 @@ impl Rule {
     pub fn action(&self) -> Action {
         self.action.clone()
     }
+
+    /// Returns who made the rule, or `None` if the game started with it.
+    pub fn author(&self) -> Option<Author> {
+        self.author
+    }
+
+    /// Returns how many penalties the rule has caused.
+    pub fn penalties(&self) -> usize {
+        self.penalties
+    }
+}
+
+impl Action {
+    /// Parses an action from the rule creation format. Eg. "skip", "draw 2", "pass left", "forbid" or "say hello {value}" (or "say \"hello {value}\"").
+    ///
+    /// Returns `Err` with a description of the problem if `str` isn't a valid action.
+    pub fn parse(str: &str) -> Result<Self, String> {
+        let s = str.trim().to_lowercase();
+        let words: Vec<_> = s.split_whitespace().collect();
+
+        match words[..] {
+            ["draw"] => Ok(Self::Draw(1)),
+            ["draw", n] => parse_count(n).map(Self::Draw),
+            ["next", "draws"] => Ok(Self::DrawNext(1)),
+            ["next", "draws", n] => parse_count(n).map(Self::DrawNext),
+            ["pass", "left"] => Ok(Self::Pass(Side::Left)),
+            ["pass", "right"] => Ok(Self::Pass(Side::Right)),
+            ["swap", "hands"] => Ok(Self::SwapHands),
+            ["repeat"] => Ok(Self::Repeat),
+            ["reverse"] => Ok(Self::Reverse),
+            ["skip"] => Ok(Self::Skip(1)),
+            ["skip", n] => parse_count(n).map(Self::Skip),
+            ["allow"] => Ok(Self::Allow),
+            ["forbid"] => Ok(Self::Forbid),
+            _ => {
+                // `s` is trimmed, so with the ending whitespace in "say ", it's known there will be non whitespace after that, and so it can safely be assumed that `quote` will be non-empty
+                let quote = s
+                    .strip_prefix("say ")
+                    .ok_or_else(|| format!("'{}' is invalid", s))?
+                    .trim();
+                // The message can be in quotes, like in a rules file.
+                let quote = quote
+                    .strip_prefix('"')
+                    .and_then(|quote| quote.strip_suffix('"'))
+                    .unwrap_or(quote)
+                    .to_string();
+
+                if quote.contains('.') {
+                    return Err(format!("'{}' may not contain '.'", &quote));
+                }
+                // Any card will do, it's only checking that the message can be filled in.
+                fill_message(&quote, Card::new(Value::Ace, Suit::Spades))?;
+                Ok(Self::Say(quote))
+            }
+        }
+    }
+
+    /// Returns the argument of the action, if it has one.
+    pub fn value(&self) -> Option<ActionValue> {
+        match self {
+            Self::Say(msg) => Some(ActionValue::String(msg.clone())),
+            Self::Draw(n) | Self::DrawNext(n) | Self::Skip(n) => Some(ActionValue::U32(*n)),
+            Self::Pass(side) => Some(ActionValue::Side(*side)),
+            Self::SwapHands | Self::Repeat | Self::Reverse | Self::Allow | Self::Forbid => None,
+        }
+    }
 }
 
 impl PartialEq for Rule {
@@ -68,15 +296,547 @@ impl PartialEq for Rule {
 
 impl Eq for Rule {}
 
+impl Display for Rule {
+    /// Writes the rule in the format `Rule::parse` reads, eg. `on value 7 say "have a nice day"`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "on {} {}", self.event, self.action)
+    }
+}
+
+impl Display for Event {
+    /// Writes the event in the format `Event::parse` reads, with brackets only where they're needed.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Values are written as numbers, except for aces and face cards.
+        let value = |value: &Value| match value.count() {
+            2..=10 => value.count().to_string(),
+            _ => value.full_name().to_lowercase(),
+        };
+
+        match self {
+            Self::CardPlayed(card) => write!(
+                f,
+                "card {} of {}",
+                value(&card.value()),
+                card.suit().full_name().to_lowercase()
+            ),
+            Self::ValuePlayed(v) => write!(f, "value {}", value(v)),
+            Self::SuitPlayed(suit) => write!(f, "suit {}", suit.full_name().to_lowercase()),
+            Self::RedPlayed => write!(f, "red"),
+            Self::BlackPlayed => write!(f, "black"),
+            Self::FacePlayed => write!(f, "face card"),
+            Self::SameValueAsPrevious => write!(f, "same value"),
+            Self::SameSuitAsPrevious => write!(f, "same suit"),
+            Self::InARow(event, 2) => write!(f, "{} twice in a row", event.bracketed(3)),
+            Self::InARow(event, n) => write!(f, "{} {} in a row", event.bracketed(3), n),
+            Self::Run(n) => write!(f, "run of {}", n),
+            Self::ColorChanged => write!(f, "colour changed"),
+            Self::CardsLeft(n) => write!(f, "{} cards left", n),
+            Self::CardDrawn => write!(f, "draw"),
+            Self::Penalized => write!(f, "penalty"),
+            Self::OrderReversed => write!(f, "reversed"),
+            Self::RoundStarted => write!(f, "round start"),
+            Self::And(a, b) => write!(f, "{} and {}", a.bracketed(1), b.bracketed(2)),
+            Self::Or(a, b) => write!(f, "{} or {}", a, b.bracketed(1)),
+            Self::Not(a) => write!(f, "not {}", a.bracketed(2)),
+        }
+    }
+}
+
+impl Display for Action {
+    /// Writes the action in the format `Action::parse` reads, eg. `draw 2` or `say "hello"`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Counts of 1 are left out, eg. "skip" rather than "skip 1".
+        let count = |n: &u32| match n {
+            1 => String::new(),
+            n => format!(" {}", n),
+        };
+
+        match self {
+            Self::Say(msg) => write!(f, "say \"{}\"", msg),
+            Self::Draw(n) => write!(f, "draw{}", count(n)),
+            Self::DrawNext(n) => write!(f, "next draws{}", count(n)),
+            Self::Pass(Side::Left) => write!(f, "pass left"),
+            Self::Pass(Side::Right) => write!(f, "pass right"),
+            Self::SwapHands => write!(f, "swap hands"),
+            Self::Repeat => write!(f, "repeat"),
+            Self::Reverse => write!(f, "reverse"),
+            Self::Skip(n) => write!(f, "skip{}", count(n)),
+            Self::Allow => write!(f, "allow"),
+            Self::Forbid => write!(f, "forbid"),
+        }
+    }
+}
+
+impl Hash for Rule {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.event.hash(state);
+        self.action.hash(state);
+    }
+}
+
 impl Event {
-    /// Checks if the arg matches the corresponding value in `card`.
+    /// Parses an event from the rule creation format. Eg. "card is 4 of spades", or "red and not value is 7".
+    ///
+    /// A condition is `<type> is <data>` or `<type> <data>` (the type being card, suit or value), `red`, `black` or `face card`, or just the name of a suit or value. Conditions can also look at the cards played before: `same value`, `same suit`, `colour changed`, `run of <n>`, or any condition followed by `<n> in a row` (or `twice in a row`), and at the player's hand: `<n> cards left`. Rather than a card being played, a condition can also be `draw`, `penalty`, `reversed` or `round start`. Conditions can be combined with `and`, `or` and `not`, where `not` binds the tightest and `or` the loosest, and grouped with brackets.
+    ///
+    /// Returns `Err` with a description of the problem if `str` isn't a valid event, if it has more than `MAX_CONTEXT_EVENTS` different conditions about the cards played before or the player's hand, or if no card could ever trigger it.
+    pub fn parse(str: &str) -> Result<Self, String> {
+        let tokens = tokenize(str);
+        let mut parser = EventParser {
+            tokens: &tokens,
+            pos: 0,
+        };
+
+        let event = parser.or()?;
+        if let Some(token) = parser.peek() {
+            return Err(format!("unexpected '{}' in '{}'", token, str.trim()));
+        }
+        let mut context = vec![];
+        event.context_events(&mut context);
+        if context.len() > MAX_CONTEXT_EVENTS {
+            return Err(format!(
+                "'{}' looks back at the game {} different ways, but only {} are allowed",
+                str.trim(),
+                context.len(),
+                MAX_CONTEXT_EVENTS
+            ));
+        }
+        if !event.is_possible() {
+            return Err(format!("'{}' can never happen", str.trim()));
+        }
+
+        Ok(event)
+    }
+
+    /// Parses a single condition, without any `and`, `or` or `not`.
+    fn parse_condition(str: &str) -> Result<Self, String> {
+        let str = str.trim().to_lowercase();
+
+        // "value 7" is the same as "value is 7".
+        let typed = str
+            .split_once(' ')
+            .filter(|(typ, _)| ["card", "suit", "value"].contains(typ));
+        let Some((typ, data)) = str.split_once(" is ").or(typed) else {
+            // Shorthands: "red", "spades", "7", "4 of spades", ...
+            return match str.as_str() {
+                "red" => Some(Self::RedPlayed),
+                "black" => Some(Self::BlackPlayed),
+                "face" | "face card" => Some(Self::FacePlayed),
+                "same value" | "same value as previous" => Some(Self::SameValueAsPrevious),
+                "same suit" | "same suit as previous" => Some(Self::SameSuitAsPrevious),
+                "color changed" | "colour changed" => Some(Self::ColorChanged),
+                "draw" | "drew" | "card drawn" => Some(Self::CardDrawn),
+                "penalty" | "penalized" => Some(Self::Penalized),
+                "reversed" | "order reversed" => Some(Self::OrderReversed),
+                "round start" | "round started" => Some(Self::RoundStarted),
+                "no cards left" => Some(Self::CardsLeft(0)),
+                s if s.ends_with(" left") => s
+                    .strip_suffix(" cards left")
+                    .or_else(|| s.strip_suffix(" card left"))
+                    .and_then(|n| n.trim().parse().ok())
+                    .map(Self::CardsLeft),
+                s if s.starts_with("run of ") => {
+                    return match s["run of ".len()..].trim().parse() {
+                        Ok(n) if (2..=13).contains(&n) => Ok(Self::Run(n)),
+                        _ => Err(format!("'{}' has to be a run of 2 to 13 cards", s)),
+                    };
+                }
+                s => s
+                    .parse()
+                    .map(Self::SuitPlayed)
+                    .or_else(|_| s.parse().map(Self::ValuePlayed))
+                    .ok()
+                    .or_else(|| parse_card(s).map(Self::CardPlayed)),
+            }
+            .ok_or_else(|| format!("'{}' is missing ' is '", str));
+        };
+        let typ = typ.trim();
+        let data = data.trim();
+
+        match typ {
+            "card" => parse_card(data).map(Self::CardPlayed),
+            "suit" => data.parse().ok().map(Self::SuitPlayed),
+            "value" => data.parse().ok().map(Self::ValuePlayed),
+            _ => None,
+        }
+        .ok_or_else(|| format!("'{}' is invalid for type '{}'", data, typ))
+    }
+
+    /// Checks if the arg matches the corresponding value in `card`, as if it was the only card played so far, by a player with plenty of cards left.
     ///
     /// Example: `Event::ValuePlayed(Value::Ace)` and `Card::new(Value::Ace, Suit::Spades)` would match
     pub fn arg_matches(&self, card: Card) -> bool {
+        self.matches(&Situation {
+            trigger: Trigger::CardPlayed,
+            played: &[card],
+            cards_left: usize::MAX,
+        })
+    }
+
+    /// Checks if the event happens in `situation`.
+    pub fn matches(&self, situation: &Situation) -> bool {
+        let card = match situation.trigger {
+            Trigger::CardPlayed => situation.played.first().copied(),
+            _ => None,
+        };
+        self.eval_for(situation.trigger, card, &|event| {
+            event.context_matches(situation)
+        })
+    }
+
+    /// Returns every trigger that can set off the event.
+    pub fn triggers(&self) -> Vec<Trigger> {
+        match self {
+            Self::CardDrawn => vec![Trigger::CardDrawn],
+            Self::Penalized => vec![Trigger::Penalty],
+            Self::OrderReversed => vec![Trigger::Reversed],
+            Self::RoundStarted => vec![Trigger::RoundStarted],
+            Self::And(a, b) | Self::Or(a, b) => {
+                let mut triggers = a.triggers();
+                for trigger in b.triggers() {
+                    if !triggers.contains(&trigger) {
+                        triggers.push(trigger);
+                    }
+                }
+                triggers
+            }
+            Self::Not(a) => a.triggers(),
+            _ => vec![Trigger::CardPlayed],
+        }
+    }
+
+    /// Checks the event for `trigger` (with `card` if one was played), if it's one of the event's triggers.
+    fn eval_for(
+        &self,
+        trigger: Trigger,
+        card: Option<Card>,
+        known: &dyn Fn(&Event) -> bool,
+    ) -> bool {
+        self.triggers().contains(&trigger) && self.eval(trigger, card, known)
+    }
+
+    /// Checks the event against `trigger` and `card`, asking `known` about anything that depends on more than the card (see `Event::context_matches`).
+    fn eval(&self, trigger: Trigger, card: Option<Card>, known: &dyn Fn(&Event) -> bool) -> bool {
+        match self {
+            Self::CardPlayed(arg) => card.is_some_and(|card| arg == &card),
+            Self::ValuePlayed(arg) => card.is_some_and(|card| arg == &card.value()),
+            Self::SuitPlayed(arg) => {
+                card.is_some_and(|card| !card.is_joker() && arg == &card.suit())
+            }
+            Self::RedPlayed => card.is_some_and(|card| card.suit().is_red()),
+            Self::BlackPlayed => card.is_some_and(|card| !card.suit().is_red()),
+            Self::FacePlayed => card.is_some_and(|card| card.value().is_face()),
+            // The card played has to be one of the ones in a row.
+            Self::InARow(event, _) => event.eval(trigger, card, known) && known(self),
+            Self::SameValueAsPrevious
+            | Self::SameSuitAsPrevious
+            | Self::Run(_)
+            | Self::ColorChanged
+            | Self::CardsLeft(_) => card.is_some() && known(self),
+            Self::CardDrawn => trigger == Trigger::CardDrawn,
+            Self::Penalized => trigger == Trigger::Penalty,
+            Self::OrderReversed => trigger == Trigger::Reversed,
+            Self::RoundStarted => trigger == Trigger::RoundStarted,
+            Self::And(a, b) => a.eval(trigger, card, known) && b.eval(trigger, card, known),
+            Self::Or(a, b) => a.eval(trigger, card, known) || b.eval(trigger, card, known),
+            Self::Not(a) => !a.eval(trigger, card, known),
+        }
+    }
+
+    /// Checks an event that depends on more than the card played against `situation`: the cards played before it, or the player's hand. Any other event is `false`.
+    fn context_matches(&self, situation: &Situation) -> bool {
+        let played = situation.played;
+        match self {
+            Self::SameValueAsPrevious => {
+                played.len() >= 2 && played[0].value() == played[1].value()
+            }
+            Self::SameSuitAsPrevious => {
+                played.len() >= 2
+                    && !played[0].is_joker()
+                    && !played[1].is_joker()
+                    && played[0].suit() == played[1].suit()
+            }
+            Self::ColorChanged => {
+                played.len() >= 2 && played[0].suit().is_red() != played[1].suit().is_red()
+            }
+            Self::InARow(event, n) => {
+                played.len() >= *n
+                    && (0..*n).all(|i| {
+                        event.matches(&Situation {
+                            played: &played[i..],
+                            ..*situation
+                        })
+                    })
+            }
+            Self::Run(n) => {
+                let steps = played
+                    .get(..*n)
+                    .map(|run| {
+                        run.windows(2)
+                            .map(|w| w[0].value().count() as i8 - w[1].value().count() as i8)
+                            .collect::<Vec<_>>()
+                    })
+                    .unwrap_or_default();
+                // Jokers don't have a place in a run.
+                !steps.is_empty()
+                    && !played[..*n].iter().any(Card::is_joker)
+                    && (steps.iter().all(|&step| step == 1) || steps.iter().all(|&step| step == -1))
+            }
+            Self::CardsLeft(n) => situation.cards_left == *n,
+            _ => false,
+        }
+    }
+
+    /// Adds every event in the event that depends on more than the card played (see `Event::context_matches`) to `found`, without repeats.
+    fn context_events(&self, found: &mut Vec<Event>) {
         match self {
-            Self::CardPlayed(arg) => arg == &card,
-            Self::ValuePlayed(arg) => arg == &card.value(),
-            Self::SuitPlayed(arg) => arg == &card.suit(),
+            Self::InARow(event, _) | Self::Not(event) => event.context_events(found),
+            Self::And(a, b) | Self::Or(a, b) => {
+                a.context_events(found);
+                b.context_events(found);
+            }
+            _ => {}
+        }
+
+        let is_context = matches!(
+            self,
+            Self::SameValueAsPrevious
+                | Self::SameSuitAsPrevious
+                | Self::InARow(..)
+                | Self::Run(_)
+                | Self::ColorChanged
+                | Self::CardsLeft(_)
+        );
+        if is_context && !found.contains(self) {
+            found.push(self.clone());
+        }
+    }
+
+    /// Calls `f` with every situation `events` could be in: each trigger, and for cards being played, each card with each combination of the events' context events (see `Event::context_events`) having happened or not. Returns whether `f` returned `true` for any of them.
+    ///
+    /// Context events are treated as independent of each other, so some of the situations can't really happen. Parsed events have at most `MAX_CONTEXT_EVENTS` each, but events made some other way could have more, so only the first `MAX_CONTEXT_EVENTS` of each event are tried both ways, and the rest are taken not to have happened.
+    fn any_situation(
+        events: &[&Event],
+        f: impl Fn(Trigger, Option<Card>, &dyn Fn(&Event) -> bool) -> bool,
+    ) -> bool {
+        let mut context = vec![];
+        for event in events {
+            let mut own = vec![];
+            event.context_events(&mut own);
+            for found in own.into_iter().take(MAX_CONTEXT_EVENTS) {
+                if !context.contains(&found) {
+                    context.push(found);
+                }
+            }
+        }
+
+        all::<Trigger>().any(|trigger| {
+            if trigger != Trigger::CardPlayed {
+                return f(trigger, None, &|_| false);
+            }
+
+            Card::every().any(|card| {
+                (0..1u64 << context.len()).any(|happened| {
+                    f(trigger, Some(card), &|event| {
+                        context
+                            .iter()
+                            .position(|e| e == event)
+                            .is_some_and(|i| happened & (1 << i) != 0)
+                    })
+                })
+            })
+        })
+    }
+
+    /// Returns the event written out (see `Display`), in brackets if it would otherwise bind less tightly than `precedence`: 0 for `or`, 1 for `and`, 2 for `not` and 3 for `in a row`.
+    fn bracketed(&self, precedence: u8) -> String {
+        let own = match self {
+            Self::Or(..) => 0,
+            Self::And(..) => 1,
+            Self::Not(_) => 2,
+            Self::InARow(..) => 3,
+            _ => 4,
+        };
+
+        if own < precedence {
+            format!("({})", self)
+        } else {
+            self.to_string()
+        }
+    }
+
+    /// Whether the event can ever happen.
+    pub fn is_possible(&self) -> bool {
+        Self::any_situation(&[self], |trigger, card, known| {
+            self.eval_for(trigger, card, known)
+        })
+    }
+
+    /// Whether there is a situation that matches both events.
+    pub fn overlaps(&self, other: &Event) -> bool {
+        Self::any_situation(&[self, other], |trigger, card, known| {
+            self.eval_for(trigger, card, known) && other.eval_for(trigger, card, known)
+        })
+    }
+
+    /// Whether every situation that matches `other` also matches this event, so `other` could never happen without this one happening too.
+    pub fn covers(&self, other: &Event) -> bool {
+        !Self::any_situation(&[self, other], |trigger, card, known| {
+            other.eval_for(trigger, card, known) && !self.eval_for(trigger, card, known)
+        })
+    }
+}
+
+/// What the `{card}`, `{value}` and `{suit}` of a `Say` message are filled in with.
+#[derive(Debug, Serialize)]
+struct MessageContext {
+    card: String,
+    value: String,
+    suit: String,
+}
+
+/// Fills in the `{card}`, `{value}` and `{suit}` of a `Say` rule's message for `card`, eg. "I played {card}" becomes "I played ace of spades".
+///
+/// Returns `Err` with a description of the problem if the message can't be filled in, like `{oops}` or `{ nope`.
+pub fn fill_message(message: &str, card: Card) -> Result<String, String> {
+    let context = MessageContext {
+        card: card.full_name().to_lowercase(),
+        value: card.value().full_name().to_lowercase(),
+        suit: card.suit_name().to_lowercase(),
+    };
+
+    let mut template = TinyTemplate::new();
+    template
+        .add_template("message", message)
+        .and_then(|_| template.render("message", &context))
+        .map_err(|_| format!("'{message}' can only fill in {{card}}, {{value}} or {{suit}}"))
+}
+
+/// Parses the number of times an action happens, which has to be from 1 to `MAX_COUNT`.
+fn parse_count(str: &str) -> Result<u32, String> {
+    str.parse()
+        .ok()
+        .filter(|n| (1..=MAX_COUNT).contains(n))
+        .ok_or_else(|| format!("'{}' has to be a number from 1 to {}", str, MAX_COUNT))
+}
+
+/// Parses a card in the format `<value> of <suit>`. Jokers are hearts (red) or spades (black), so no other joker is a card.
+fn parse_card(str: &str) -> Option<Card> {
+    let (value, suit) = str.split_once(" of ")?;
+    Some(Card::new(
+        value.trim().parse().ok()?,
+        suit.trim().parse().ok()?,
+    ))
+    .filter(Card::is_valid)
+}
+
+/// Splits an event into brackets and words.
+fn tokenize(str: &str) -> Vec<String> {
+    str.replace('(', " ( ")
+        .replace(')', " ) ")
+        .split_whitespace()
+        .map(str::to_lowercase)
+        .collect()
+}
+
+/// A recursive descent parser for events, going through the tokens from `tokenize`.
+struct EventParser<'a> {
+    tokens: &'a [String],
+    pos: usize,
+}
+
+impl<'a> EventParser<'a> {
+    fn peek(&self) -> Option<&'a str> {
+        self.tokens.get(self.pos).map(String::as_str)
+    }
+
+    /// Moves past the next token if it's `token`, and returns whether it did.
+    fn eat(&mut self, token: &str) -> bool {
+        let found = self.peek() == Some(token);
+        if found {
+            self.pos += 1;
+        }
+        found
+    }
+
+    /// `<and> (or <and>)*`
+    fn or(&mut self) -> Result<Event, String> {
+        let mut event = self.and()?;
+        while self.eat("or") {
+            event = Event::Or(Box::new(event), Box::new(self.and()?));
+        }
+        Ok(event)
+    }
+
+    /// `<not> (and <not>)*`
+    fn and(&mut self) -> Result<Event, String> {
+        let mut event = self.not()?;
+        while self.eat("and") {
+            event = Event::And(Box::new(event), Box::new(self.not()?));
+        }
+        Ok(event)
+    }
+
+    /// `not <not> | <in a row>`
+    fn not(&mut self) -> Result<Event, String> {
+        if self.eat("not") {
+            return Ok(Event::Not(Box::new(self.not()?)));
+        }
+
+        let mut event = self.primary()?;
+        while let Some(n) = self.in_a_row()? {
+            event = Event::InARow(Box::new(event), n);
+        }
+        Ok(event)
+    }
+
+    /// `( <or> ) | <condition>`
+    fn primary(&mut self) -> Result<Event, String> {
+        if self.eat("(") {
+            let event = self.or()?;
+            if !self.eat(")") {
+                return Err("missing ')'".to_string());
+            }
+            return Ok(event);
+        }
+
+        // A condition is every word up to the next operator, bracket or "in a row".
+        let start = self.pos;
+        while let Some(token) = self.peek() {
+            if ["and", "or", "not", "(", ")"].contains(&token) || self.at_in_a_row() {
+                break;
+            }
+            self.pos += 1;
+        }
+        if start == self.pos {
+            return Err(match self.peek() {
+                Some(token) => format!("expected a condition before '{}'", token),
+                None => "expected a condition".to_string(),
+            });
+        }
+
+        Event::parse_condition(&self.tokens[start..self.pos].join(" "))
+    }
+
+    /// Whether the next tokens are `<n> in a row`.
+    fn at_in_a_row(&self) -> bool {
+        self.tokens.get(self.pos + 1..self.pos + 4) == Some(&["in", "a", "row"].map(String::from))
+    }
+
+    /// Moves past `<n> in a row` if it's next, and returns `n`.
+    fn in_a_row(&mut self) -> Result<Option<usize>, String> {
+        if !self.at_in_a_row() {
+            return Ok(None);
+        }
+        let count = &self.tokens[self.pos];
+        self.pos += 4;
+
+        match count.as_str() {
+            "twice" => Ok(Some(2)),
+            s => match s.parse() {
+                Ok(n) if n >= 2 => Ok(Some(n)),
+                _ => Err(format!("'{} in a row' has to be 2 or more in a row", s)),
+            },
         }
     }
 }
@@ -0,0 +1,31 @@
+/*
+ * Rules files let a group keep their house rules between
+ * games. A rules file has one rule per line, written as
+ * `on <event> <action>` (see "Rule::parse"), eg.
+ * `on value 7 say "have a nice day"`. Blank lines, and
+ * lines starting with '#', are left out.
+ */
+
+use crate::rule::Rule;
+
+/// The rules a game starts with, unless it's given others.
+pub const DEFAULT_RULES: &str = include_str!("rules/default");
+
+/// Parses every rule in the rules file `text`, in order.
+///
+/// Returns `Err` with the line number and a description of the problem if any line isn't a valid rule.
+pub fn parse_rules(text: &str) -> Result<Vec<Rule>, String> {
+    text.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty() && !line.trim_start().starts_with('#'))
+        .map(|(i, line)| Rule::parse(line).map_err(|issue| format!("line {}: {}", i + 1, issue)))
+        .collect()
+}
+
+/// Writes `rules` as a rules file, one rule per line, which `parse_rules` reads back as the same rules.
+pub fn write_rules<'a>(rules: impl IntoIterator<Item = &'a Rule>) -> String {
+    rules
+        .into_iter()
+        .map(|rule| format!("{}\n", rule))
+        .collect()
+}
@@ -0,0 +1,342 @@
+/*
+ * The "RuleModel" tries to work out the hidden rules of a game
+ * just from watching it be played, like a player would. Every
+ * possible rule (an Event and an Action) is a hypothesis, which
+ * is supported when what it would do happens, and refuted when
+ * it doesn't. The rules that best explain what was seen are the
+ * model's beliefs, as a "RuleMap" that can be compared to the
+ * real one.
+ */
+
+use crate::card::Card;
+use crate::game::Game;
+use crate::journal::{GameEvent, Journal};
+use crate::render::play_screen::RuleActionResult;
+use crate::rule::priority::{ActionOption, ActionValue};
+use crate::rule::rule_map::RuleMap;
+use crate::rule::{Action, Event, Rule, Side};
+use enum_iterator::all;
+use std::collections::HashMap;
+
+/// Things the default rules make everybody say when they draw, are penalized or play their last card, which the model doesn't learn.
+const BUILT_IN_PHRASES: [&str; 2] = ["thank you", "mao"];
+
+/// How much a hypothesis has been seen to hold.
+#[derive(Debug, Default, Clone, Copy)]
+struct Evidence {
+    /// The number of times it explained something that happened.
+    support: u32,
+    /// Set once it didn't do something it would have had to.
+    refuted: bool,
+}
+
+/// A card being played, and everything that came of it.
+#[derive(Debug)]
+struct Turn {
+    player: usize,
+    card: Card,
+    legal: bool,
+    /// The actions of rules that were triggered, eg. `Skip(2)` for two `Skip` results.
+    triggered: Vec<Action>,
+    /// ("Incorrect use of _"s, "Failure to say _"s) from the first penalty after the card was played.
+    mistakes: Option<(Vec<String>, Vec<String>)>,
+    /// What was said, if it's known.
+    said: Option<Vec<String>>,
+}
+
+/// A model of what the rules of a game are, inferred from the game's events.
+#[derive(Debug, Default)]
+pub struct RuleModel {
+    hypotheses: HashMap<Rule, Evidence>,
+    /// Every (card, action) that has been seen to happen, for the beliefs to explain.
+    observations: Vec<(Card, Action)>,
+    turn: Option<Turn>,
+    /// The index in the journal of the first event in the events being observed, and how many of them have been observed.
+    seen: (usize, usize),
+}
+
+impl RuleModel {
+    /// Creates a new `RuleModel` that doesn't know anything yet.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Observes every event in `journal` that hasn't been observed yet.
+    pub fn observe_journal(&mut self, journal: &Journal) {
+        self.observe_events(0, journal.events());
+    }
+
+    /// Observes every event in `events` that hasn't been observed yet, where `events` is everything that has happened since the `start`th event in the journal (eg. the round from a `PlayerView`).
+    pub fn observe_events(&mut self, start: usize, events: &[GameEvent]) {
+        let (seen_start, seen) = self.seen;
+        // A new round or a different game is read from the top.
+        let seen = if start == seen_start && events.len() >= seen {
+            seen
+        } else {
+            0
+        };
+
+        for event in &events[seen..] {
+            self.observe(event);
+        }
+        self.seen = (start, events.len());
+    }
+
+    /// Updates the model with something that happened in the game.
+    ///
+    /// Changes to the rules are ignored, since they are supposed to be secret.
+    pub fn observe(&mut self, event: &GameEvent) {
+        match event {
+            GameEvent::CardPlayed {
+                player,
+                card,
+                legal,
+            } => {
+                self.end_turn();
+                self.turn = Some(Turn {
+                    player: *player,
+                    card: *card,
+                    legal: *legal,
+                    triggered: vec![],
+                    mistakes: None,
+                    said: None,
+                });
+            }
+            GameEvent::RuleTriggered { result, .. } => {
+                if let Some(turn) = &mut self.turn {
+                    let action = result_action(result, turn.player);
+
+                    // Each player skipped is its own result, so they're added up.
+                    match turn.triggered.last_mut() {
+                        Some(Action::Skip(n)) if action == Action::Skip(1) => *n += 1,
+                        _ => turn.triggered.push(action),
+                    }
+                }
+            }
+            GameEvent::Penalty {
+                player,
+                incs,
+                fails,
+                ..
+            } => {
+                if let Some(turn) = &mut self.turn {
+                    // Only the first penalty is for what was said with the card, the rest are for what was said after being penalized.
+                    if turn.player == *player && turn.mistakes.is_none() {
+                        turn.mistakes = Some((incs.clone(), fails.clone()));
+                    }
+                }
+            }
+            GameEvent::CardDrawn { .. }
+            | GameEvent::RoundStarted { .. }
+            | GameEvent::RoundWon { .. } => self.end_turn(),
+            GameEvent::RuleCreated { .. }
+            | GameEvent::RuleRemoved { .. }
+            | GameEvent::RuleModified { .. }
+            | GameEvent::PriorityChanged { .. }
+            | GameEvent::DiscardsReshuffled { .. }
+            | GameEvent::DeckAdded { .. } => {}
+        }
+    }
+
+    /// Tells the model what was said with the card that was just played, for when it's known (eg. it was the model's own player).
+    pub fn said(&mut self, quotes: &[String]) {
+        if let Some(turn) = &mut self.turn {
+            turn.said = Some(quotes.to_vec());
+        }
+    }
+
+    /// Returns what the model believes has to be said when `card` is played (not including "thank you" or "mao").
+    pub fn phrases_for(&self, card: Card) -> Vec<String> {
+        let mut phrases: Vec<_> = self
+            .beliefs()
+            .rules()
+            .filter(|rule| rule.event().arg_matches(card))
+            .filter_map(|rule| match rule.action() {
+                Action::Say(template) => Some(Game::parse_message(template, card)),
+                _ => None,
+            })
+            .collect();
+        phrases.sort();
+        phrases.dedup();
+        phrases
+    }
+
+    /// Returns the rules the model currently believes are in the game: for everything that has been seen to happen, the best explanation that hasn't been refuted.
+    pub fn beliefs(&self) -> RuleMap {
+        let mut beliefs = RuleMap::default();
+
+        for (card, action) in &self.observations {
+            let best = events_matching(*card)
+                .into_iter()
+                .flat_map(|event| {
+                    let templates = match action {
+                        Action::Say(phrase) => templates_for(phrase, *card),
+                        action => vec![action.clone()],
+                    };
+                    templates
+                        .into_iter()
+                        .map(move |action| Rule::new(event.clone(), action))
+                })
+                .filter_map(|rule| {
+                    let evidence = self.hypotheses.get(&rule)?;
+                    (!evidence.refuted).then_some((rule, evidence.support))
+                })
+                // `max_by_key` keeps the last of equals, which is the most general explanation.
+                .max_by_key(|(_, support)| *support);
+
+            if let Some((rule, _)) = best {
+                if !beliefs.contains(rule.clone()) {
+                    beliefs.push(rule);
+                }
+            }
+        }
+
+        beliefs
+    }
+
+    /// Finishes off the turn being watched, learning from everything that came of it.
+    fn end_turn(&mut self) {
+        let Some(turn) = self.turn.take() else {
+            return;
+        };
+        let events = events_matching(turn.card);
+
+        // Automatic actions only happen for legal cards, and always happen when a rule for them matches.
+        if turn.legal {
+            let mut actions = automatic_actions();
+            for action in &turn.triggered {
+                if !actions.contains(action) {
+                    actions.push(action.clone());
+                }
+            }
+
+            for action in actions {
+                let happened = turn.triggered.contains(&action);
+                for event in &events {
+                    self.weigh(Rule::new(event.clone(), action.clone()), happened);
+                }
+                if happened {
+                    self.observations.push((turn.card, action));
+                }
+            }
+        }
+
+        let (incs, fails) = turn.mistakes.unwrap_or_default();
+        let built_in = |phrase: &String| BUILT_IN_PHRASES.contains(&phrase.as_str());
+
+        // Everything that had to be said: what was forgotten, and what was said without it being a mistake.
+        let mut required: Vec<_> = fails.iter().filter(|p| !built_in(p)).cloned().collect();
+        if let Some(said) = &turn.said {
+            required.extend(
+                said.iter()
+                    .filter(|p| !built_in(p) && !incs.contains(p))
+                    .cloned(),
+            );
+        }
+
+        for phrase in &required {
+            for template in templates_for(phrase, turn.card) {
+                for event in &events {
+                    self.weigh(Rule::new(event.clone(), template.clone()), true);
+                }
+            }
+            self.observations
+                .push((turn.card, Action::Say(phrase.clone())));
+        }
+
+        // Things said that didn't have to be, weren't required by anything that matches this card.
+        for phrase in incs.iter().filter(|p| !built_in(p)) {
+            for template in templates_for(phrase, turn.card) {
+                for event in &events {
+                    self.weigh(Rule::new(event.clone(), template.clone()), false);
+                }
+            }
+        }
+
+        // If everything that had to be said is known, anything else that would have had to be said is wrong.
+        if turn.said.is_some() {
+            for (rule, evidence) in self.hypotheses.iter_mut() {
+                if let Action::Say(template) = rule.action() {
+                    if rule.event().arg_matches(turn.card)
+                        && !required.contains(&Game::parse_message(template, turn.card))
+                    {
+                        evidence.refuted = true;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Adds evidence for (`held`) or against `rule`.
+    fn weigh(&mut self, rule: Rule, held: bool) {
+        let evidence = self.hypotheses.entry(rule).or_default();
+        if held {
+            evidence.support += 1;
+        } else {
+            evidence.refuted = true;
+        }
+    }
+}
+
+/// Every event that would be triggered by `card`, from the most specific to the most general.
+fn events_matching(card: Card) -> Vec<Event> {
+    let mut events = vec![Event::CardPlayed(card), Event::ValuePlayed(card.value())];
+    // Jokers have no suit.
+    if !card.is_joker() {
+        events.push(Event::SuitPlayed(card.suit()));
+    }
+    events
+}
+
+/// Every `Action::Say` that would say `phrase` when `card` is played, from the most specific to the most general.
+fn templates_for(phrase: &str, card: Card) -> Vec<Action> {
+    let value = card.value().full_name().to_lowercase();
+    let suit = card.suit_name().to_lowercase();
+    let card_name = card.full_name().to_lowercase();
+
+    let mut templates = vec![phrase.to_string()];
+    for template in [
+        phrase.replace(&value, "{value}"),
+        phrase.replace(&suit, "{suit}"),
+        phrase.replace(&value, "{value}").replace(&suit, "{suit}"),
+        phrase.replace(&card_name, "{card}"),
+    ] {
+        if !templates.contains(&template) {
+            templates.push(template);
+        }
+    }
+
+    templates.into_iter().map(Action::Say).collect()
+}
+
+/// The action that would have caused `result`, after `player`'s turn.
+fn result_action(result: &RuleActionResult, player: usize) -> Action {
+    match result {
+        RuleActionResult::Draw { who, cards } if *who == player => Action::Draw(cards.len() as u32),
+        RuleActionResult::Draw { cards, .. } => Action::DrawNext(cards.len() as u32),
+        RuleActionResult::Pass { side, .. } => Action::Pass(*side),
+        RuleActionResult::SwapHands { .. } => Action::SwapHands,
+        RuleActionResult::Repeat { .. } => Action::Repeat,
+        RuleActionResult::Reverse => Action::Reverse,
+        RuleActionResult::Skip { .. } => Action::Skip(1),
+    }
+}
+
+/// Every action that happens without anybody having to say anything, with the smallest argument it can have.
+fn automatic_actions() -> Vec<Action> {
+    all::<ActionOption>()
+        .flat_map(|option| match option {
+            // Legality rules don't do anything after the turn.
+            ActionOption::Say | ActionOption::Allow | ActionOption::Forbid => vec![],
+            ActionOption::Draw | ActionOption::DrawNext | ActionOption::Skip => {
+                vec![option.action(Some(ActionValue::U32(1)))]
+            }
+            ActionOption::Pass => vec![
+                option.action(Some(ActionValue::Side(Side::Left))),
+                option.action(Some(ActionValue::Side(Side::Right))),
+            ],
+            _ => vec![option.action(None)],
+        })
+        .flatten()
+        .collect()
+}
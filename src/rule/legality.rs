@@ -0,0 +1,57 @@
+/*
+ * A "PlayPolicy" is the basic rule for which cards can be
+ * played on top of others, which everybody is told about.
+ * Legality rules (rules with an "Allow" or "Forbid" action)
+ * are kept in the "RuleMap" along with it, and can make a
+ * card legal or illegal to play no matter what the policy
+ * says, eg. "queens are wild" or "no jack on a jack".
+ */
+
+use crate::card::Card;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// Which cards can be played on top of the used pile, before any legality rules are applied.
+///
+/// Jokers are wild: they can be played on any card, and any card can be played on them.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PlayPolicy {
+    /// The card has to be the same colour or the same value as the top card.
+    #[default]
+    SameColorOrValue,
+    /// The card has to be the same suit or the same value as the top card, like in most games of Mao.
+    SameSuitOrValue,
+    /// Any card can be played, so only `Forbid` rules make a card illegal.
+    AnyCard,
+}
+
+impl PlayPolicy {
+    /// Whether `card` can be played on top of `top`.
+    pub fn allows(&self, top: Card, card: Card) -> bool {
+        if top.is_joker() || card.is_joker() {
+            return true;
+        }
+
+        match self {
+            Self::SameColorOrValue => {
+                top.suit().is_red() == card.suit().is_red() || top.value() == card.value()
+            }
+            Self::SameSuitOrValue => top.suit() == card.suit() || top.value() == card.value(),
+            Self::AnyCard => true,
+        }
+    }
+}
+
+impl FromStr for PlayPolicy {
+    type Err = ();
+
+    /// Parses a policy from the command line format: `colour` (or `color`), `suit` or `any`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "colour" | "color" => Ok(Self::SameColorOrValue),
+            "suit" => Ok(Self::SameSuitOrValue),
+            "any" => Ok(Self::AnyCard),
+            _ => Err(()),
+        }
+    }
+}
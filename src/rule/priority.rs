@@ -1,9 +1,9 @@
 /* Created by Om Sharma
  * 
  * This program has a "Priority" struct which represents
- * the order of which rules would be applied. As of now,
- * only the default order is used in the game, but for a
- * future expansion, the order could be randomized each game.
+ * the order of which rules would be applied. A game uses
+ * the default order unless it's given another one (or a
+ * random one), and the winner of a round can reorder it.
  * 
  * ActionOption is an enum which corresponds to Actions,
  * but don't do anything. The main difference is in the
@@ -11,29 +11,42 @@
  * (the message), while in `ActionOption::Say` doesn't.
 */
 
-use crate::rule::Action;
+use crate::rule::{Action, Side};
 use enum_iterator::{all, Sequence};
+use rand::seq::SliceRandom;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
 use std::cmp::{Eq, PartialEq};
+use std::fmt::Display;
 use std::hash::Hash;
 use std::iter::Iterator;
+use std::str::FromStr;
 
 /// A struct representing the order of actions to be executed when rules are applied on a turn.
-#[derive(Debug, Clone, PartialEq, Hash)]
+#[derive(Debug, Clone, PartialEq, Hash, Serialize, Deserialize)]
 pub struct Priority {
     queue: Vec<ActionOption>,
 }
 
 impl Priority {
     /// Creates a new `Priority` instance from the given order.
-    pub fn new(order_queue: Vec<ActionOption>) -> Self {
-        Self { queue: order_queue }
+    ///
+    /// Returns `Err(IncompletePriority)` unless every `ActionOption` is in it exactly once.
+    pub fn new(order_queue: Vec<ActionOption>) -> Result<Self, IncompletePriority> {
+        let priority = Self { queue: order_queue };
+        if priority.is_complete() {
+            Ok(priority)
+        } else {
+            Err(IncompletePriority)
+        }
     }
 
-    /// Creates a default `Priority` object, which is the order of `ActionOption`s.
-    pub fn default() -> Self {
-        Self {
-            queue: all::<ActionOption>().collect(),
-        }
+    /// Returns whether every `ActionOption` is in the priority exactly once. The rules with an action that's missing would never happen, so only a complete priority can be used by a game.
+    ///
+    /// Priorities are always complete, unless they were deserialized from somewhere else (eg. a player over the network).
+    pub fn is_complete(&self) -> bool {
+        self.queue.len() == all::<ActionOption>().count()
+            && all::<ActionOption>().all(|option| self.queue.contains(&option))
     }
 
     /// Gets the number of action options in the object.
@@ -41,51 +54,164 @@ impl Priority {
         self.queue.len()
     }
 
+    /// Returns whether there are no action options in the object.
+    pub fn is_empty(&self) -> bool {
+        self.queue.is_empty()
+    }
+
     /// Iterates over values (as references).
-    pub fn iter(&self) -> std::slice::Iter<ActionOption> {
+    pub fn iter(&self) -> std::slice::Iter<'_, ActionOption> {
         self.queue.iter()
     }
+
+    /// Creates a `Priority` with every `ActionOption` in a random order.
+    pub fn shuffled<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        let mut queue: Vec<_> = all::<ActionOption>().collect();
+        queue.shuffle(rng);
+
+        Self { queue }
+    }
+
+    /// Returns a copy of the priority with `first` moved to the front, in that order. The rest keep their order after them.
+    pub fn reordered(&self, first: &[ActionOption]) -> Self {
+        let mut queue = vec![];
+        for option in first.iter().chain(self.queue.iter()) {
+            if !queue.contains(option) {
+                queue.push(*option);
+            }
+        }
+
+        Self { queue }
+    }
+
+    /// Reorders the priority from the reordering format: the actions to go first, separated by commas. Eg. "skip, reverse" makes skips happen first, then reverses, then everything else as before.
+    ///
+    /// Returns `Err` with a description of the problem if one of the actions isn't valid.
+    pub fn parse_reorder(&self, str: &str) -> Result<Self, String> {
+        let first = str
+            .split(',')
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+            .map(|name| {
+                name.parse()
+                    .map_err(|_| format!("'{}' is not an action", name))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(self.reordered(&first))
+    }
+}
+
+impl Display for Priority {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let names: Vec<_> = self.queue.iter().map(ActionOption::name).collect();
+        write!(f, "{}", names.join(", "))
+    }
+}
+
+impl Default for Priority {
+    /// Creates a default `Priority` object, which is the order of `ActionOption`s.
+    fn default() -> Self {
+        Self {
+            queue: all::<ActionOption>().collect(),
+        }
+    }
 }
 
 impl Eq for Priority {}
 
+/// The error for a priority that doesn't have every `ActionOption` exactly once (see `Priority::is_complete`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IncompletePriority;
+
+impl Display for IncompletePriority {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "A priority has to have every action exactly once!")
+    }
+}
+impl std::error::Error for IncompletePriority {}
+
 /// Options for an `Action`. The order of action execution is the order of the variants.
-#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Sequence)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Sequence, Serialize, Deserialize)]
 pub enum ActionOption {
     Say,
     Draw,
+    DrawNext,
+    Pass,
+    SwapHands,
     Repeat,
     Reverse,
     Skip,
+    Allow,
+    Forbid,
 }
 
 impl ActionOption {
-    /// Returns the corrsponding `Action` for the `ActionOption`.
-    fn action(self, value: String) -> Action {
+    /// Returns the corrsponding `Action` for the `ActionOption`, with the argument `value`. Returns `None` if the action needs a different kind of argument (see `Action::value`).
+    pub fn action(self, value: Option<ActionValue>) -> Option<Action> {
+        Some(match (self, value) {
+            (Self::Say, Some(ActionValue::String(msg))) => Action::Say(msg),
+            (Self::Draw, Some(ActionValue::U32(n))) => Action::Draw(n),
+            (Self::DrawNext, Some(ActionValue::U32(n))) => Action::DrawNext(n),
+            (Self::Pass, Some(ActionValue::Side(side))) => Action::Pass(side),
+            (Self::SwapHands, None) => Action::SwapHands,
+            (Self::Repeat, None) => Action::Repeat,
+            (Self::Reverse, None) => Action::Reverse,
+            (Self::Skip, Some(ActionValue::U32(n))) => Action::Skip(n),
+            (Self::Allow, None) => Action::Allow,
+            (Self::Forbid, None) => Action::Forbid,
+            _ => return None,
+        })
+    }
+
+    /// Returns the name of the option, as in the rule creation format. Eg. "next draws".
+    pub fn name(&self) -> &'static str {
         match self {
-            Self::Say => Action::Say(value),
-            Self::Draw => Action::Draw,
-            Self::Repeat => Action::Repeat,
-            Self::Reverse => Action::Reverse,
-            Self::Skip => Action::Skip,
+            Self::Say => "say",
+            Self::Draw => "draw",
+            Self::DrawNext => "next draws",
+            Self::Pass => "pass",
+            Self::SwapHands => "swap hands",
+            Self::Repeat => "repeat",
+            Self::Reverse => "reverse",
+            Self::Skip => "skip",
+            Self::Allow => "allow",
+            Self::Forbid => "forbid",
         }
     }
 }
 
+impl FromStr for ActionOption {
+    type Err = ();
+
+    /// Parses an option from its name, ignoring case. Eg. "Swap hands".
+    fn from_str(str: &str) -> Result<Self, Self::Err> {
+        let str = str.trim().to_lowercase();
+        all::<Self>().find(|option| option.name() == str).ok_or(())
+    }
+}
+
 impl From<Action> for ActionOption {
     fn from(action: Action) -> Self {
         match action {
             Action::Say(_) => ActionOption::Say,
-            Action::Draw => ActionOption::Draw,
+            Action::Draw(_) => ActionOption::Draw,
+            Action::DrawNext(_) => ActionOption::DrawNext,
+            Action::Pass(_) => ActionOption::Pass,
+            Action::SwapHands => ActionOption::SwapHands,
             Action::Repeat => ActionOption::Repeat,
             Action::Reverse => ActionOption::Reverse,
-            Action::Skip => ActionOption::Skip,
+            Action::Skip(_) => ActionOption::Skip,
+            Action::Allow => ActionOption::Allow,
+            Action::Forbid => ActionOption::Forbid,
         }
     }
 }
 
-#[derive(Debug)]
+/// The argument of an `Action`.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ActionValue {
-  String(String),
-  U32(u32)
+    String(String),
+    U32(u32),
+    Side(Side),
 }
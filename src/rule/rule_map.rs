@@ -8,35 +8,31 @@
  * Action.
  */
 
+use crate::rule::legality::PlayPolicy;
 use crate::rule::priority::ActionOption;
-use crate::rule::Rule;
+use crate::rule::{Rule, Situation};
 use enum_iterator::all;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 /// The key type for a `RuleMap`.
 type Key = ActionOption;
 
 /// A struct mapping an `ActionOption` to a collection of rules that have that action.
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct RuleMap {
     map: HashMap<Key, Vec<Rule>>,
+    #[serde(default)]
+    play_policy: PlayPolicy, // Which cards can be played, before `Allow` and `Forbid` rules
 }
 
 impl RuleMap {
     /// Creates a new `RuleMap` from the given `map`.
     pub fn new(map: HashMap<Key, Vec<Rule>>) -> Self {
-        Self { map }
-    }
-
-    /// Creates a new `RuleMap` from the `ActionOptions`.
-    pub fn default() -> Self {
-        let mut map = HashMap::new();
-
-        for option in all::<Key>() {
-            map.insert(option, vec![]);
+        Self {
+            map,
+            play_policy: PlayPolicy::default(),
         }
-
-        Self { map }
     }
 
     /// Pushes `rule` to end of the vector at key `option`, or returns an error.
@@ -46,6 +42,28 @@ impl RuleMap {
         Some(())
     }
 
+    /// Pushes `rule` to the end of the vector for its action.
+    pub fn push(&mut self, rule: Rule) {
+        self.map
+            .entry(ActionOption::from(rule.action()))
+            .or_default()
+            .push(rule);
+    }
+
+    /// Counts a penalty against the rule with the same event and action as `rule`, if there is one.
+    pub fn blame(&mut self, rule: &Rule) {
+        if let Some(rule) = self.map.values_mut().flatten().find(|r| *r == rule) {
+            rule.penalties += 1;
+        }
+    }
+
+    /// Iterates over every rule, in the order of the `ActionOption`s.
+    pub fn rules(&self) -> impl Iterator<Item = &Rule> {
+        all::<Key>()
+            .filter_map(|option| self.map.get(&option))
+            .flatten()
+    }
+
     /// Empties the rules vector for key `option`.
     pub fn empty_vec(&mut self, option: Key) -> Option<()> {
         self.map.get_mut(&option)?.clear();
@@ -64,10 +82,7 @@ impl RuleMap {
     }
 
     pub fn validate_option(&self, option: ActionOption) -> bool {
-        match self.map.get(&option) {
-            Some(_) => true,
-            None => false,
-        }
+        self.map.contains_key(&option)
     }
 
     pub fn contains(&self, rule: Rule) -> bool {
@@ -90,4 +105,47 @@ impl RuleMap {
 
         None
     }
+
+    /// Returns the basic rule for which cards can be played.
+    pub fn play_policy(&self) -> PlayPolicy {
+        self.play_policy
+    }
+
+    /// Sets the basic rule for which cards can be played. The `Allow` and `Forbid` rules are kept.
+    pub fn set_play_policy(&mut self, play_policy: PlayPolicy) {
+        self.play_policy = play_policy;
+    }
+
+    /// Whether the card just played in `situation` (the first card of `situation.played`, on top of the rest) is a legal play. It has to be allowed by the play policy or an `Allow` rule, and not by any `Forbid` rule.
+    pub fn allows(&self, situation: &Situation) -> bool {
+        let (Some(&card), Some(&top)) = (situation.played.first(), situation.played.get(1)) else {
+            return true;
+        };
+        let triggered = |option| {
+            self.map
+                .get(&option)
+                .into_iter()
+                .flatten()
+                .any(|rule| rule.event().matches(situation))
+        };
+
+        (self.play_policy.allows(top, card) || triggered(ActionOption::Allow))
+            && !triggered(ActionOption::Forbid)
+    }
+}
+
+impl Default for RuleMap {
+    /// Creates a new `RuleMap` from the `ActionOptions`.
+    fn default() -> Self {
+        let mut map = HashMap::new();
+
+        for option in all::<Key>() {
+            map.insert(option, vec![]);
+        }
+
+        Self {
+            map,
+            play_policy: PlayPolicy::default(),
+        }
+    }
 }
@@ -0,0 +1,312 @@
+/*
+ * The simulator plays lots of games between bots, with nothing
+ * rendered, and sums up what happened in them from each game's
+ * journal. It's meant for trying out a rule set before playing
+ * it for real: how long rounds take, how much drawing there is,
+ * which rules get people penalized the most, etc.
+ */
+
+use crate::card::Card;
+use crate::game::config::GameConfig;
+use crate::game::Game;
+use crate::interface::bot::BotKind;
+use crate::interface::table::TableInterface;
+use crate::interface::{MistakeReport, Move, Notice, PlayerInterface};
+use crate::journal::GameEvent;
+use crate::player::Player;
+use crate::render::engine::{RenderError, RenderResult};
+use crate::rule::priority::Priority;
+use crate::rule::rule_map::RuleMap;
+use crate::rule::{Action, Rule, RuleChange};
+use crate::view::PlayerView;
+use std::collections::BTreeMap;
+use std::fmt::Display;
+
+/// The settings of a simulation.
+#[derive(Debug, Clone)]
+pub struct SimConfig {
+    /// The number of games to play.
+    pub games: usize,
+    /// The number of rounds in each game.
+    pub rounds: usize,
+    /// The bots playing, one for each seat.
+    pub bots: Vec<BotKind>,
+    /// The seed of the first game, each game after it uses the next one.
+    pub seed: u64,
+    /// The rules to play with, or `None` for the default rules.
+    pub rule_map: Option<RuleMap>,
+    /// A round is given up on after this many turns (or penalties), so a rule set that never lets anyone win can't run forever.
+    pub max_turns: usize,
+    /// The settings each game is set up with.
+    pub game: GameConfig,
+}
+
+impl Default for SimConfig {
+    fn default() -> Self {
+        Self {
+            games: 1000,
+            rounds: 1,
+            bots: vec![BotKind::Easy; 4],
+            seed: 0,
+            rule_map: None,
+            max_turns: 1000,
+            game: GameConfig::default(),
+        }
+    }
+}
+
+/// Everything that happened over a simulation, added up.
+#[derive(Debug, Clone, Default)]
+pub struct SimStats {
+    /// The rules that were played with.
+    pub rules: Vec<Rule>,
+    pub games: usize,
+    /// Rounds that somebody won.
+    pub rounds: usize,
+    /// Rounds given up on after too many turns.
+    pub unfinished_rounds: usize,
+    /// Turns taken in the rounds that somebody won.
+    pub turns: usize,
+    /// Cards drawn by players on their turn (not as penalties or from rules).
+    pub draws: usize,
+    /// The number of times each thing was penalized, by what it was for.
+    pub penalties: BTreeMap<String, usize>,
+    /// The number of times the used pile was shuffled back into the draw pile.
+    pub reshuffles: usize,
+    /// The number of times a new deck had to be added to the draw pile.
+    pub decks_added: usize,
+    /// The number of rounds won from each seat. The player in seat 0 goes first, unless the games are set up otherwise (see `GameConfig::starting_player`).
+    pub wins_by_seat: Vec<usize>,
+}
+
+impl SimStats {
+    /// The average number of turns in a round.
+    pub fn avg_round_length(&self) -> f64 {
+        self.turns as f64 / self.rounds.max(1) as f64
+    }
+
+    /// The average number of cards each player draws on their turn, per round.
+    pub fn draws_per_player(&self) -> f64 {
+        self.draws as f64 / (self.rounds.max(1) * self.wins_by_seat.len().max(1)) as f64
+    }
+
+    /// How much more often the first player wins than they would if every seat was as likely to win. `1.0` is no advantage.
+    pub fn first_player_advantage(&self) -> f64 {
+        let first = self.wins_by_seat.first().copied().unwrap_or_default() as f64;
+        let fair = self.rounds as f64 / self.wins_by_seat.len().max(1) as f64;
+        if fair == 0.0 {
+            1.0
+        } else {
+            first / fair
+        }
+    }
+}
+
+/// Plays all the games of the simulation described by `config`, and returns the stats of them.
+pub fn simulate(config: &SimConfig) -> SimStats {
+    let mut stats = SimStats {
+        wins_by_seat: vec![0; config.bots.len()],
+        ..SimStats::default()
+    };
+
+    for n in 0..config.games {
+        let players: Vec<_> = config
+            .bots
+            .iter()
+            .enumerate()
+            .map(|(i, kind)| Player::bot(format!("Bot {}", i + 1), *kind))
+            .collect();
+        let mut game = Game::with_config(
+            players,
+            config.seed.wrapping_add(n as u64),
+            config.game.clone(),
+        );
+        if let Some(rule_map) = &config.rule_map {
+            game.set_rule_map(rule_map.clone());
+        }
+        if n == 0 {
+            stats.rules = game.rule_map().rules().cloned().collect();
+        }
+
+        let mut table = TableInterface::bots_only(game.players(), game.seed());
+        for _ in 0..config.rounds {
+            let mut limited = TurnLimit {
+                inner: &mut table,
+                turns_left: config.max_turns,
+            };
+            match game.round(&mut limited) {
+                Ok(winner) => stats.wins_by_seat[winner] += 1,
+                // Giving up on a round leaves the game in the middle of it, so the rest of it can't be played.
+                Err(_) => {
+                    stats.unfinished_rounds += 1;
+                    break;
+                }
+            }
+        }
+
+        stats.games += 1;
+        tally(&game, &mut stats);
+    }
+
+    stats
+}
+
+/// Adds everything in `game`'s journal to `stats`, and the penalties each `Say` rule caused.
+fn tally(game: &Game, stats: &mut SimStats) {
+    let mut turns = 0;
+    let mut draws = 0;
+
+    for event in game.journal().events() {
+        match event {
+            GameEvent::RoundStarted { .. } => {
+                turns = 0;
+                draws = 0;
+            }
+            GameEvent::CardPlayed { .. } => turns += 1,
+            GameEvent::CardDrawn { .. } => {
+                turns += 1;
+                draws += 1;
+            }
+            GameEvent::Penalty {
+                incs, card_error, ..
+            } => {
+                let mut count = |reason: String| *stats.penalties.entry(reason).or_default() += 1;
+
+                if *card_error {
+                    count("playing an illegal card".to_string());
+                }
+                for inc in incs {
+                    count(format!("incorrect use of \"{inc}\""));
+                }
+            }
+            GameEvent::DiscardsReshuffled { .. } => stats.reshuffles += 1,
+            GameEvent::DeckAdded { .. } => stats.decks_added += 1,
+            GameEvent::RoundWon { .. } => {
+                // Only finished rounds count towards round lengths.
+                stats.rounds += 1;
+                stats.turns += turns;
+                stats.draws += draws;
+            }
+            GameEvent::RuleTriggered { .. }
+            | GameEvent::RuleCreated { .. }
+            | GameEvent::RuleRemoved { .. }
+            | GameEvent::RuleModified { .. }
+            | GameEvent::PriorityChanged { .. } => {}
+        }
+    }
+
+    // Failing to say something is blamed on the rules that asked for it as it happens (see `Rule::penalties`), which can't be worked out from the journal afterwards.
+    for rule in game.rule_map().rules() {
+        if matches!(rule.action(), Action::Say(_)) && rule.penalties() > 0 {
+            *stats.penalties.entry(rule.to_string()).or_default() += rule.penalties();
+        }
+    }
+}
+
+impl Display for SimStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Rules:")?;
+        for rule in &self.rules {
+            writeln!(f, "  {rule}")?;
+        }
+        writeln!(f)?;
+        writeln!(
+            f,
+            "Games: {}, rounds won: {}, rounds given up on: {}",
+            self.games, self.rounds, self.unfinished_rounds
+        )?;
+        writeln!(
+            f,
+            "Average round length: {:.1} turns",
+            self.avg_round_length()
+        )?;
+        writeln!(
+            f,
+            "Cards drawn per player per round: {:.2}",
+            self.draws_per_player()
+        )?;
+        writeln!(
+            f,
+            "Used pile shuffled into the draw pile: {}",
+            self.reshuffles
+        )?;
+        writeln!(f, "New decks added to the draw pile: {}", self.decks_added)?;
+        writeln!(
+            f,
+            "First player advantage: {:.2}x (wins by seat: {:?})",
+            self.first_player_advantage(),
+            self.wins_by_seat
+        )?;
+        writeln!(f, "Penalties:")?;
+
+        let mut penalties: Vec<_> = self.penalties.iter().collect();
+        penalties.sort_by(|a, b| b.1.cmp(a.1));
+        for (reason, n) in penalties {
+            writeln!(f, "  {n:>8}  {reason}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Passes everything on to `inner`, until it has been asked for `turns_left` moves and penalty speeches. Penalties count too, since a rule set can make a player keep being penalized for what they say (eg. "thank you", when it isn't a rule).
+struct TurnLimit<'a, 'b> {
+    inner: &'a mut TableInterface<'b>,
+    turns_left: usize,
+}
+
+impl<'a, 'b> TurnLimit<'a, 'b> {
+    /// Uses up one of the turns left, or fails if there aren't any.
+    fn count_turn(&mut self) -> RenderResult<()> {
+        if self.turns_left == 0 {
+            return Err(RenderError::ScriptEnded);
+        }
+        self.turns_left -= 1;
+        Ok(())
+    }
+}
+
+impl<'a, 'b> PlayerInterface for TurnLimit<'a, 'b> {
+    fn notify(&mut self, view: &PlayerView, notice: Notice) -> RenderResult<()> {
+        self.inner.notify(view, notice)
+    }
+
+    fn request_move(&mut self, view: &PlayerView) -> RenderResult<Move> {
+        self.count_turn()?;
+        self.inner.request_move(view)
+    }
+
+    fn request_speech(
+        &mut self,
+        view: &PlayerView,
+        did_draw: bool,
+        action_card: Card,
+    ) -> RenderResult<Vec<String>> {
+        self.inner.request_speech(view, did_draw, action_card)
+    }
+
+    fn request_penalty_speech(
+        &mut self,
+        view: &PlayerView,
+        report: &MistakeReport,
+    ) -> RenderResult<Vec<String>> {
+        self.count_turn()?;
+        self.inner.request_penalty_speech(view, report)
+    }
+
+    fn confirm_next_round(&mut self, view: &PlayerView) -> RenderResult<bool> {
+        self.inner.confirm_next_round(view)
+    }
+
+    fn request_rule(&mut self, view: &PlayerView, winner: usize) -> RenderResult<RuleChange> {
+        self.inner.request_rule(view, winner)
+    }
+
+    fn request_priority(
+        &mut self,
+        view: &PlayerView,
+        winner: usize,
+    ) -> RenderResult<Option<Priority>> {
+        self.inner.request_priority(view, winner)
+    }
+}
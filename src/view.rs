@@ -0,0 +1,191 @@
+/*
+ * A "PlayerView" is the game as one player can see it: their
+ * own hand, how many cards everybody else has, the top of the
+ * used pile, and everything that happened in front of them
+ * this round.
+ * Every screen and interface is given a view instead of the
+ * "Game" itself, so nobody can see a card they shouldn't.
+ */
+
+use crate::card::Card;
+use crate::game::Game;
+use crate::journal::GameEvent;
+use crate::rule::legality::PlayPolicy;
+use crate::rule::priority::Priority;
+use crate::rule::Rule;
+use serde::{Deserialize, Serialize};
+
+/// What everybody can see of a player: who they are and how many cards they have.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlayerSummary {
+    name: String,
+    num_cards: usize,
+}
+
+impl PlayerSummary {
+    /// Returns this player's name.
+    pub fn name(&self) -> &String {
+        &self.name
+    }
+
+    /// Returns the number of cards in this player's hand.
+    pub fn num_cards(&self) -> usize {
+        self.num_cards
+    }
+}
+
+/// The game as seen by one player, or by everybody at once.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlayerView {
+    viewer: Option<usize>, // `None` if everybody is looking
+    hand: Vec<Card>,
+    players: Vec<PlayerSummary>,
+    player_index: usize,
+    round_n: usize,
+    round_over: bool,
+    used_top: Vec<Card>, // The top two cards of the used pile, top first
+    play_policy: PlayPolicy,
+    priority: Priority,
+    events_start: usize, // The index in the game's journal of the first of `events`
+    events: Vec<GameEvent>,
+    own_rules: Vec<Rule>,
+}
+
+impl PlayerView {
+    /// Creates the view of `game` that `viewer` has, or that everybody has if it's `None`.
+    pub fn new(game: &Game, viewer: Option<usize>) -> Self {
+        Self {
+            viewer,
+            hand: viewer
+                .map(|i| game.players()[i].hand().clone())
+                .unwrap_or_default(),
+            players: game
+                .players()
+                .iter()
+                .map(|player| PlayerSummary {
+                    name: player.name().clone(),
+                    num_cards: player.num_cards(),
+                })
+                .collect(),
+            player_index: game.player_index(),
+            round_n: game.round_n(),
+            round_over: game.round_over(),
+            used_top: game.used_pile().cards().iter().take(2).copied().collect(),
+            play_policy: game.rule_map().play_policy(),
+            priority: game.priority().clone(),
+            events_start: game.journal().round_start(),
+            events: game
+                .journal()
+                .round_events()
+                .iter()
+                .filter_map(|event| event.seen_by(viewer))
+                .collect(),
+            own_rules: viewer
+                .map(|viewer| own_rules(game.journal().events_of(viewer)))
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Returns the player this is the view of, or `None` if it's what everybody can see.
+    pub fn viewer(&self) -> Option<usize> {
+        self.viewer
+    }
+
+    /// Returns the viewer's hand. It's empty if the view is everybody's.
+    pub fn hand(&self) -> &Vec<Card> {
+        &self.hand
+    }
+
+    /// Returns what can be seen of every player.
+    pub fn players(&self) -> &Vec<PlayerSummary> {
+        &self.players
+    }
+
+    /// Returns what can be seen of the current player.
+    pub fn current_player(&self) -> &PlayerSummary {
+        &self.players[self.player_index]
+    }
+
+    /// Returns the index of the current player.
+    pub fn player_index(&self) -> usize {
+        self.player_index
+    }
+
+    /// Returns the number of the current round, counting from 1. It is 0 before the first round.
+    pub fn round_n(&self) -> usize {
+        self.round_n
+    }
+
+    /// Returns whether or not the round is over.
+    pub fn round_over(&self) -> bool {
+        self.round_over
+    }
+
+    /// Returns the card on top of the used pile, or `None` if it's empty.
+    pub fn top_card(&self) -> Option<Card> {
+        self.used_top.first().copied()
+    }
+
+    /// Returns the card under the top of the used pile, which was on top before the last card was played.
+    pub fn under_top_card(&self) -> Option<Card> {
+        self.used_top.get(1).copied()
+    }
+
+    /// Returns everything the viewer has seen happen in the round so far, in order, starting with the round being dealt. Cards drawn by other players are face down (`None`), and changes to the rules are left out unless the viewer made them.
+    ///
+    /// Only the round is kept, so views stay small however long the game goes on.
+    pub fn events(&self) -> &[GameEvent] {
+        &self.events
+    }
+
+    /// Returns the index in the game's journal of the first of `events`, which is the same for every view of a round.
+    pub fn events_start(&self) -> usize {
+        self.events_start
+    }
+
+    /// Returns the basic rule for which cards can be played, which everybody is told about.
+    pub fn play_policy(&self) -> PlayPolicy {
+        self.play_policy
+    }
+
+    /// Returns the rules the viewer has made (or modified) that they haven't removed, in the order they made them. Nobody is shown anyone else's rules, so some of them may have been removed or modified by somebody else since.
+    pub fn own_rules(&self) -> Vec<Rule> {
+        self.own_rules.clone()
+    }
+
+    /// Returns the order the rules' actions are applied in.
+    pub fn priority(&self) -> &Priority {
+        &self.priority
+    }
+
+    /// Returns the indices of the cards in the viewer's hand that are legal to play by the play policy. The legality rules are secret, so some of them may not really be.
+    pub fn legal_plays(&self) -> Vec<usize> {
+        self.hand
+            .iter()
+            .enumerate()
+            .filter(|(_, card)| {
+                // Anything can be played on an empty used pile.
+                self.top_card()
+                    .is_none_or(|top| self.play_policy.allows(top, **card))
+            })
+            .map(|(i, _)| i)
+            .collect()
+    }
+}
+
+/// Returns the rules made by the changes to the rules in `events` that are still there after the rest of them, in the order they were made.
+fn own_rules<'a>(events: impl Iterator<Item = &'a GameEvent>) -> Vec<Rule> {
+    let mut rules = vec![];
+    for event in events {
+        match event {
+            GameEvent::RuleCreated { rule, .. } => rules.push(rule.clone()),
+            GameEvent::RuleRemoved { rule, .. } => rules.retain(|own| own != rule),
+            GameEvent::RuleModified { old, new, .. } => {
+                rules.retain(|own| own != old);
+                rules.push(new.clone());
+            }
+            _ => {}
+        }
+    }
+    rules
+}
@@ -0,0 +1,153 @@
+use mao::card::{Card, Suit, Value};
+use mao::game::Game;
+use mao::interface::scripted::ScriptedInterface;
+use mao::journal::GameEvent;
+use mao::player::Player;
+use mao::players;
+use mao::render::play_screen::RuleActionResult;
+use mao::rule::priority::{ActionOption, ActionValue};
+use mao::rule::{Action, Event, Rule, Side, MAX_COUNT};
+
+/// Plays `script` on a new game for `players` with a rule doing `action` whenever a card is drawn, and returns what the rules did.
+fn results(players: Vec<Player>, action: Action, script: &str) -> Vec<RuleActionResult> {
+    let mut game = Game::new(players);
+    game.add_rule(Rule::new(Event::parse("draw").unwrap(), action))
+        .unwrap();
+
+    assert!(game.play(&mut ScriptedInterface::new(script)).is_err());
+    game.journal()
+        .events()
+        .iter()
+        .filter_map(|event| match event {
+            GameEvent::RuleTriggered { result, .. } => Some(result.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn actions_parse() {
+    assert_eq!(Action::parse("draw"), Ok(Action::Draw(1)));
+    assert_eq!(Action::parse("draw 3"), Ok(Action::Draw(3)));
+    assert_eq!(Action::parse("Skip 2"), Ok(Action::Skip(2)));
+    assert_eq!(Action::parse("next draws 2"), Ok(Action::DrawNext(2)));
+    assert_eq!(Action::parse("pass left"), Ok(Action::Pass(Side::Left)));
+    assert_eq!(Action::parse(" pass right "), Ok(Action::Pass(Side::Right)));
+    assert_eq!(Action::parse("swap hands"), Ok(Action::SwapHands));
+    assert_eq!(
+        Action::parse("say draw 2"),
+        Ok(Action::Say("draw 2".to_string()))
+    );
+
+    assert!(Action::parse("draw 0").is_err());
+    assert_eq!(Action::parse("draw 10"), Ok(Action::Draw(MAX_COUNT)));
+    assert!(Action::parse("skip 4000000000").is_err());
+    assert!(Action::parse("skip -1").is_err());
+    assert!(Action::parse("pass up").is_err());
+}
+
+#[test]
+fn actions_round_trip_through_options() {
+    let actions = [
+        Action::Say("hello".to_string()),
+        Action::Draw(3),
+        Action::DrawNext(2),
+        Action::Pass(Side::Right),
+        Action::SwapHands,
+        Action::Repeat,
+        Action::Reverse,
+        Action::Skip(2),
+    ];
+
+    for action in actions {
+        let option = ActionOption::from(action.clone());
+        assert_eq!(option.action(action.value()), Some(action));
+    }
+    assert_eq!(
+        ActionOption::SwapHands.action(Some(ActionValue::U32(2))),
+        None
+    );
+    assert_eq!(ActionOption::Draw.action(None), None);
+}
+
+#[test]
+fn drawing_rules_deal_to_the_right_player() {
+    let drawn = |results: &[RuleActionResult]| match results.first() {
+        Some(RuleActionResult::Draw { who, cards }) => Some((*who, cards.len())),
+        _ => None,
+    };
+
+    let own = results(players!["Alice", "Bob"], Action::Draw(2), "d\nthank you");
+    assert_eq!(drawn(&own), Some((0, 2)));
+
+    let next = results(
+        players!["Alice", "Bob"],
+        Action::DrawNext(3),
+        "d\nthank you",
+    );
+    assert_eq!(drawn(&next), Some((1, 3)));
+}
+
+#[test]
+fn skipping_more_than_one_player() {
+    let results = results(
+        players!["Alice", "Bob", "Carol"],
+        Action::Skip(2),
+        "d\nthank you\nd\nthank you",
+    );
+
+    // Alice skips Bob and Carol, so she draws again and skips them again.
+    let skipped: Vec<_> = results
+        .iter()
+        .filter_map(|result| match result {
+            RuleActionResult::Skip { who } => Some(*who),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(skipped, vec![1, 2, 1, 2]);
+}
+
+#[test]
+fn huge_counts_are_cut_down() {
+    let results = results(
+        players!["Alice", "Bob"],
+        Action::Skip(u32::MAX),
+        "d\nthank you",
+    );
+
+    let skips = results
+        .iter()
+        .filter(|result| matches!(result, RuleActionResult::Skip { .. }))
+        .count();
+    assert_eq!(skips, MAX_COUNT as usize);
+}
+
+#[test]
+fn moved_cards_are_hidden_from_others() {
+    let card = Card::new(Value::Two, Suit::Hearts);
+    let pass = RuleActionResult::Pass {
+        from: 0,
+        to: 1,
+        side: Side::Left,
+        card: Some(card),
+    };
+
+    assert_eq!(pass.seen_by(Some(1)), pass);
+    assert!(matches!(
+        pass.seen_by(Some(2)),
+        RuleActionResult::Pass { card: None, .. }
+    ));
+
+    let draw = RuleActionResult::Draw {
+        who: 0,
+        cards: vec![Some(card); 2],
+    };
+    assert_eq!(draw.seen_by(Some(0)), draw);
+    assert_eq!(
+        draw.seen_by(None),
+        RuleActionResult::Draw {
+            who: 0,
+            cards: vec![None; 2]
+        }
+    );
+}
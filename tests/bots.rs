@@ -0,0 +1,76 @@
+mod common;
+
+use common::bots;
+use mao::card::{Card, Suit, Value};
+use mao::game::Game;
+use mao::interface::bot::{BotKind, LearningBot};
+use mao::interface::table::TableInterface;
+use mao::interface::{MistakeReport, PlayerInterface};
+
+/// A report for being penalized for `fails`.
+fn failed_to_say(fails: &[&str]) -> MistakeReport {
+    MistakeReport {
+        incs: vec![],
+        current_incs: 0,
+        fails: fails.iter().map(|s| s.to_string()).collect(),
+        current_fails: fails.len(),
+        drawn: vec![],
+        card_error: false,
+        current_card_error: false,
+    }
+}
+
+#[test]
+fn bot_kinds_parse_from_names() {
+    assert_eq!("bot:easy".parse(), Ok(BotKind::Easy));
+    assert_eq!("Bot:Hard".parse(), Ok(BotKind::Hard));
+    assert!("Alice".parse::<BotKind>().is_err());
+}
+
+#[test]
+fn easy_bots_only_play_legal_cards() {
+    let mut game = Game::with_seed(bots(3, BotKind::Easy), 8);
+    let mut table = TableInterface::bots_only(game.players(), game.seed());
+
+    let winner = game.round(&mut table).unwrap();
+
+    assert_eq!(game.players()[winner].num_cards(), 0);
+    assert!(game.journal().events().iter().all(|event| !matches!(
+        event,
+        mao::journal::GameEvent::CardPlayed { legal: false, .. }
+    )));
+}
+
+#[test]
+fn hard_bots_finish_a_round() {
+    let mut game = Game::with_seed(bots(3, BotKind::Hard), 21);
+    let mut table = TableInterface::bots_only(game.players(), game.seed());
+
+    let winner = game.round(&mut table).unwrap();
+
+    assert_eq!(game.players()[winner].num_cards(), 0);
+}
+
+#[test]
+fn learning_bot_learns_from_its_penalties() {
+    let mut game = Game::with_seed(bots(3, BotKind::Hard), 1);
+    game.start_round();
+    let view = game.view(0);
+    let mut bot = LearningBot::new(1);
+    let seven = Card::new(Value::Seven, Suit::Hearts);
+
+    // It doesn't know to say anything yet.
+    assert!(bot.request_speech(&view, false, seven).unwrap().is_empty());
+    bot.request_penalty_speech(&view, &failed_to_say(&["have a nice day"]))
+        .unwrap();
+
+    // Now it does, for any 7.
+    let other_seven = Card::new(Value::Seven, Suit::Clubs);
+    assert_eq!(
+        bot.request_speech(&view, false, other_seven).unwrap(),
+        vec!["have a nice day".to_string()]
+    );
+    // But not for things that have nothing in common with the first 7.
+    let four = Card::new(Value::Four, Suit::Spades);
+    assert!(bot.request_speech(&view, false, four).unwrap().is_empty());
+}
@@ -0,0 +1,77 @@
+mod common;
+
+use common::bots;
+use mao::card::{Card, Suit, Value};
+use mao::deck::Deck;
+use mao::game::config::GameConfig;
+use mao::game::invariant::CardCount;
+use mao::game::Game;
+use mao::interface::bot::BotKind;
+use mao::interface::table::TableInterface;
+use mao::players;
+
+#[test]
+fn counting_cards() {
+    let ace = Card::new(Value::Ace, Suit::Spades);
+    let two = Card::new(Value::Two, Suit::Hearts);
+    let deck = CardCount::of(&Deck::default_52());
+    assert_eq!(deck.total(), 52);
+    assert_eq!(deck.count(ace), 1);
+
+    let mut cards = deck.clone();
+    cards.add(&[ace, ace]);
+    let mismatch = deck.compare(&cards, "cheating").unwrap_err();
+    assert_eq!(mismatch.created, vec![ace, ace]);
+    assert!(mismatch.lost.is_empty());
+
+    let mismatch = CardCount::of(&[two])
+        .compare(&CardCount::of(&[ace]), "swapping")
+        .unwrap_err();
+    assert_eq!((mismatch.created, mismatch.lost), (vec![ace], vec![two]));
+    assert!(deck.compare(&deck, "nothing").is_ok());
+}
+
+#[test]
+fn games_keep_their_cards() {
+    // Big hands, so the used pile is shuffled back and decks are added.
+    let config = GameConfig::default().with_hand_size(15);
+    let mut game = Game::with_config(bots(3, BotKind::Hard), 8, config);
+    let mut table = TableInterface::bots_only(game.players(), game.seed());
+    assert!(game.check_cards("starting").is_ok());
+
+    for _ in 0..2 {
+        game.start_round();
+        assert!(game.check_cards("dealing").is_ok());
+        game.round(&mut table).unwrap();
+        assert!(game.check_cards("the round").is_ok());
+    }
+}
+
+#[test]
+fn changed_cards_are_found() {
+    let mut game = Game::with_seed(players!["Alice", "Bob"], 2);
+    game.start_round();
+
+    let card = game.current_player_mut().mut_hand().pop().unwrap();
+    let mismatch = game.check_cards("losing a card").unwrap_err();
+    assert_eq!((mismatch.created, mismatch.lost), (vec![], vec![card]));
+
+    game.current_player_mut().mut_hand().extend([card, card]);
+    let mismatch = game.check_cards("copying a card").unwrap_err();
+    assert!(mismatch.to_string().contains("copying a card"));
+    assert_eq!((mismatch.created, mismatch.lost), (vec![card], vec![]));
+}
+
+#[test]
+#[should_panic(expected = "cards changed while")]
+fn changed_cards_stop_the_game() {
+    let mut game = Game::with_seed(bots(3, BotKind::Hard), 2);
+    let mut table = TableInterface::bots_only(game.players(), game.seed());
+    game.start_round();
+
+    game.current_player_mut().mut_hand().clear();
+    game.current_player_mut()
+        .mut_hand()
+        .push(Card::new(Value::Ace, Suit::Spades));
+    game.round(&mut table).unwrap();
+}
@@ -0,0 +1,14 @@
+/*
+ * Helpers shared by the integration tests. Each test file that
+ * uses them declares `mod common;`.
+ */
+
+use mao::interface::bot::BotKind;
+use mao::player::Player;
+
+/// Returns `n` bots of `kind`, named "Bot 1" to "Bot n".
+pub fn bots(n: usize, kind: BotKind) -> Vec<Player> {
+    (1..=n)
+        .map(|i| Player::bot(format!("Bot {i}"), kind))
+        .collect()
+}
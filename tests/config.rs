@@ -0,0 +1,87 @@
+use mao::game::config::{GameConfig, StartingPlayer};
+use mao::game::Game;
+use mao::interface::bot::BotKind;
+use mao::interface::table::TableInterface;
+use mao::player::Player;
+use mao::players;
+use mao::rule::legality::PlayPolicy;
+
+#[test]
+fn building_a_config() {
+    let config = GameConfig::default()
+        .with_hand_size(0)
+        .with_decks(3)
+        .with_starting_player(StartingPlayer::Rotate)
+        .with_default_rules(false)
+        .with_play_policy(PlayPolicy::AnyCard);
+
+    // Nobody can start with no cards.
+    assert_eq!(config.hand_size, 1);
+    assert_eq!(config.decks, 3);
+    assert_eq!(config.starting_player, StartingPlayer::Rotate);
+    assert!(!config.default_rules);
+
+    assert_eq!("winner".parse(), Ok(StartingPlayer::LastWinner));
+    assert!("second".parse::<StartingPlayer>().is_err());
+    assert_eq!("suit".parse(), Ok(PlayPolicy::SameSuitOrValue));
+    assert!("any card".parse::<PlayPolicy>().is_err());
+}
+
+#[test]
+fn dealing_by_the_config() {
+    let config = GameConfig::default().with_hand_size(5).with_decks(2);
+    let mut game = Game::with_config(players!["Alice", "Bob", "Carol"], 4, config);
+    game.start_round();
+
+    for player in game.players() {
+        assert_eq!(player.num_cards(), 5);
+    }
+    assert_eq!(game.total_cards(), 104);
+}
+
+#[test]
+fn starting_without_the_default_rules() {
+    let config = GameConfig::default()
+        .with_default_rules(false)
+        .with_play_policy(PlayPolicy::SameSuitOrValue);
+    let game = Game::with_config(players!["Alice", "Bob"], 4, config);
+
+    assert_eq!(game.rule_map().rules().count(), 0);
+    assert_eq!(game.rule_map().play_policy(), PlayPolicy::SameSuitOrValue);
+    assert_eq!(game.view(0).play_policy(), PlayPolicy::SameSuitOrValue);
+}
+
+#[test]
+fn who_goes_first() {
+    let starting = |starting_player| {
+        let config = GameConfig::default().with_starting_player(starting_player);
+        let mut game = Game::with_config(players!["Alice", "Bob", "Carol"], 4, config);
+        (0..4)
+            .map(|_| {
+                game.start_round();
+                game.player_index()
+            })
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(starting(StartingPlayer::First), vec![0, 0, 0, 0]);
+    assert_eq!(starting(StartingPlayer::Rotate), vec![0, 1, 2, 0]);
+    assert!(starting(StartingPlayer::Random).iter().all(|&p| p < 3));
+}
+
+#[test]
+fn winners_go_first() {
+    let bots = (1..=3)
+        .map(|i| Player::bot(format!("Bot {i}"), BotKind::Hard))
+        .collect();
+    let config = GameConfig::default().with_starting_player(StartingPlayer::LastWinner);
+    let mut game = Game::with_config(bots, 21, config);
+    let mut table = TableInterface::bots_only(game.players(), game.seed());
+
+    game.start_round();
+    assert_eq!(game.player_index(), 0);
+    let winner = game.round(&mut table).unwrap();
+
+    game.start_round();
+    assert_eq!(game.player_index(), winner);
+}
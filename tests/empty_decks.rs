@@ -0,0 +1,52 @@
+use mao::card::{Card, Suit, Value};
+use mao::deck::{Deck, DeckError};
+use mao::player::Player;
+
+fn hearts(values: &[Value]) -> Deck {
+    Deck::from(
+        values
+            .iter()
+            .map(|&value| Card::new(value, Suit::Hearts))
+            .collect::<Vec<_>>(),
+    )
+}
+
+#[test]
+fn empty_decks_have_no_cards_to_look_at() {
+    let deck = Deck::empty();
+    assert_eq!(deck.top(), None);
+    assert_eq!(deck.bottom(), None);
+    assert_eq!(deck.get(0), None);
+
+    let deck = hearts(&[Value::Two, Value::Three]);
+    assert_eq!(deck.top(), Some(Card::new(Value::Two, Suit::Hearts)));
+    assert_eq!(deck.bottom(), Some(Card::new(Value::Three, Suit::Hearts)));
+    assert_eq!(deck.get(2), None);
+}
+
+#[test]
+fn taking_too_many_cards() {
+    let mut deck = hearts(&[Value::Two, Value::Three]);
+    let mut player = Player::new("Alice".to_string());
+
+    // Nothing is dealt if there aren't enough cards.
+    let err = deck.try_deal(3, &mut player).unwrap_err();
+    assert_eq!(err, DeckError::NotEnoughCards { wanted: 3, left: 2 });
+    assert_eq!(err.to_string(), "Wanted 3 cards from a deck with only 2!");
+    assert_eq!((deck.size(), player.num_cards()), (2, 0));
+
+    // But whatever is there is injected.
+    let mut used = Deck::empty();
+    assert_eq!(
+        deck.inject(3, &mut used),
+        Err(DeckError::NotEnoughCards { wanted: 3, left: 2 })
+    );
+    assert_eq!((deck.size(), used.size()), (0, 2));
+
+    assert!(player.draw(&mut deck).is_err());
+    assert_eq!(
+        player.draw(&mut used),
+        Ok(Card::new(Value::Three, Suit::Hearts))
+    );
+    assert_eq!(used.split_off(5).size(), 0);
+}
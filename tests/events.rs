@@ -0,0 +1,86 @@
+use mao::card::{Card, Suit, Value};
+use mao::game::{AddingRuleError, Game};
+use mao::players;
+use mao::rule::{Action, Event, Rule};
+
+#[test]
+fn compound_events_parse() {
+    assert_eq!(
+        Event::parse("red AND face card"),
+        Ok(Event::And(
+            Box::new(Event::RedPlayed),
+            Box::new(Event::FacePlayed)
+        ))
+    );
+    assert_eq!(
+        Event::parse("value is 7 or value is 8"),
+        Ok(Event::Or(
+            Box::new(Event::ValuePlayed(Value::Seven)),
+            Box::new(Event::ValuePlayed(Value::Eight))
+        ))
+    );
+    assert_eq!(
+        Event::parse("NOT spades"),
+        Ok(Event::Not(Box::new(Event::SuitPlayed(Suit::Spades))))
+    );
+}
+
+#[test]
+fn and_binds_tighter_than_or() {
+    let event = Event::parse("value is 7 or red and face").unwrap();
+    assert!(event.arg_matches(Card::new(Value::Seven, Suit::Spades)));
+    assert!(event.arg_matches(Card::new(Value::King, Suit::Hearts)));
+    assert!(!event.arg_matches(Card::new(Value::King, Suit::Spades)));
+
+    let event = Event::parse("(value is 7 or red) and face").unwrap();
+    assert!(!event.arg_matches(Card::new(Value::Seven, Suit::Spades)));
+    assert!(event.arg_matches(Card::new(Value::King, Suit::Hearts)));
+}
+
+#[test]
+fn bad_events_are_rejected() {
+    assert!(Event::parse("red and").is_err());
+    assert!(Event::parse("(red or black").is_err());
+    assert!(Event::parse("suit is red").is_err());
+    assert_eq!(
+        Event::parse("red and spades"),
+        Err("'red and spades' can never happen".to_string())
+    );
+}
+
+#[test]
+fn covered_rules_conflict() {
+    let mut game = Game::new(players!["Alice", "Bob"]);
+    let skip = |event: &str| Rule::new(Event::parse(event).unwrap(), Action::Skip(1));
+
+    // Aces are already skipped, by the default rules.
+    assert!(matches!(
+        game.add_rule(skip("ace and red")),
+        Err(AddingRuleError::ConflictingAction)
+    ));
+    assert!(game.add_rule(skip("face card")).is_ok());
+    assert!(matches!(
+        game.add_rule(skip("value is k or value is q")),
+        Err(AddingRuleError::ConflictingAction)
+    ));
+    // Only partly covered, so it still does something.
+    assert!(game.add_rule(skip("king or 3")).is_ok());
+}
+
+#[test]
+fn overlapping_say_rules_conflict() {
+    let mut game = Game::new(players!["Alice", "Bob"]);
+    let say = |event: &str, msg: &str| {
+        Rule::new(Event::parse(event).unwrap(), Action::Say(msg.to_string()))
+    };
+
+    // "have a nice day" is already said for every 7.
+    assert!(matches!(
+        game.add_rule(say("red or value is 7", "have a nice day")),
+        Err(AddingRuleError::ConflictingAction)
+    ));
+    assert!(game
+        .add_rule(say("red and not 7", "have a nice day"))
+        .is_ok());
+    assert!(game.add_rule(say("value is 7", "seven")).is_ok());
+}
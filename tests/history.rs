@@ -0,0 +1,149 @@
+use mao::card::{Card, Suit, Value};
+use mao::game::{AddingRuleError, Game};
+use mao::players;
+use mao::rule::{Action, Event, Rule, Situation, Trigger, MAX_CONTEXT_EVENTS};
+
+/// The situation after the first card of `played` is played on the rest.
+fn after(played: &[Card]) -> Situation<'_> {
+    Situation {
+        trigger: Trigger::CardPlayed,
+        played,
+        cards_left: 5,
+    }
+}
+
+/// What has to be said when the first card of `played` is played on the rest, with the default rules.
+fn said_for(played: &[Card]) -> Vec<String> {
+    let game = Game::new(players!["Alice", "Bob"]);
+    game.rule_map()
+        .rules()
+        .filter(|rule| rule.event().matches(&after(played)))
+        .filter_map(|rule| match rule.action() {
+            Action::Say(msg) => Some(Game::parse_message(msg, played[0])),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn history_events_parse() {
+    assert_eq!(
+        Event::parse("7 twice in a row"),
+        Ok(Event::InARow(Box::new(Event::ValuePlayed(Value::Seven)), 2))
+    );
+    assert_eq!(
+        Event::parse("not (red or face) 3 in a row"),
+        Ok(Event::Not(Box::new(Event::InARow(
+            Box::new(Event::Or(
+                Box::new(Event::RedPlayed),
+                Box::new(Event::FacePlayed)
+            )),
+            3
+        ))))
+    );
+    assert_eq!(Event::parse("run of 3"), Ok(Event::Run(3)));
+    assert_eq!(Event::parse("same value"), Ok(Event::SameValueAsPrevious));
+    assert_eq!(Event::parse("colour changed"), Ok(Event::ColorChanged));
+
+    assert!(Event::parse("red 1 in a row").is_err());
+    assert!(Event::parse("run of 14").is_err());
+}
+
+#[test]
+fn history_events_look_at_the_pile() {
+    let spades = |value| Card::new(value, Suit::Spades);
+    let hearts = |value| Card::new(value, Suit::Hearts);
+
+    let event = Event::parse("same value").unwrap();
+    assert!(event.matches(&after(&[spades(Value::Four), hearts(Value::Four)])));
+    assert!(!event.matches(&after(&[spades(Value::Four), spades(Value::Five)])));
+    assert!(!event.arg_matches(spades(Value::Four)));
+
+    let event = Event::parse("colour changed").unwrap();
+    assert!(event.matches(&after(&[hearts(Value::Four), spades(Value::Four)])));
+    assert!(!event.matches(&after(&[spades(Value::Four), spades(Value::Five)])));
+
+    // The pile is newest first, so this is 4, 5, 6 going up, and then down.
+    let event = Event::parse("run of 3").unwrap();
+    let up = [spades(Value::Six), hearts(Value::Five), spades(Value::Four)];
+    assert!(event.matches(&after(&up)));
+    assert!(event.matches(&after(&[
+        spades(Value::Four),
+        hearts(Value::Five),
+        spades(Value::Six)
+    ])));
+    assert!(!event.matches(&after(&[
+        spades(Value::Four),
+        hearts(Value::Five),
+        spades(Value::Four)
+    ])));
+    assert!(!event.matches(&after(&up[..2])));
+
+    let event = Event::parse("hearts 3 in a row").unwrap();
+    let pile = [
+        hearts(Value::Two),
+        hearts(Value::Nine),
+        hearts(Value::King),
+        spades(Value::Ace),
+    ];
+    assert!(event.matches(&after(&pile)));
+    assert!(!event.matches(&after(&pile[1..])));
+}
+
+#[test]
+fn consecutive_sevens() {
+    assert_eq!(
+        said_for(&[
+            Card::new(Value::Seven, Suit::Hearts),
+            Card::new(Value::Three, Suit::Clubs)
+        ]),
+        vec!["have a nice day"]
+    );
+    assert_eq!(
+        said_for(&[
+            Card::new(Value::Seven, Suit::Hearts),
+            Card::new(Value::Seven, Suit::Clubs)
+        ]),
+        vec!["have a very nice day"]
+    );
+}
+
+#[test]
+fn history_rules_conflict() {
+    let mut game = Game::new(players!["Alice", "Bob"]);
+    let say = |event: &str, msg: &str| {
+        Rule::new(Event::parse(event).unwrap(), Action::Say(msg.to_string()))
+    };
+
+    // Said for two 7s in a row already, and so for three.
+    assert!(matches!(
+        game.add_rule(say("7 3 in a row", "have a very nice day")),
+        Err(AddingRuleError::ConflictingAction)
+    ));
+    assert!(game.add_rule(say("same value", "snap")).is_ok());
+    assert!(matches!(
+        game.add_rule(say("same value and red", "snap")),
+        Err(AddingRuleError::ConflictingAction)
+    ));
+    assert!(game.add_rule(say("not same value", "snap")).is_ok());
+}
+
+#[test]
+fn events_can_only_look_back_so_many_ways() {
+    let left = |n: usize| {
+        (1..=n)
+            .map(|i| format!("{i} cards left"))
+            .collect::<Vec<_>>()
+            .join(" or ")
+    };
+    assert!(Event::parse(&left(MAX_CONTEXT_EVENTS)).is_ok());
+    assert!(Event::parse(&left(MAX_CONTEXT_EVENTS + 1)).is_err());
+
+    // Events that weren't parsed are still checked quickly, without overflowing.
+    let many = (1..=70)
+        .map(Event::CardsLeft)
+        .reduce(|a, b| Event::Or(Box::new(a), Box::new(b)))
+        .unwrap();
+    assert!(many.is_possible());
+    assert!(many.overlaps(&many));
+}
@@ -0,0 +1,114 @@
+use mao::card::{Card, Suit, Value};
+use mao::game::Game;
+use mao::interface::bot::BotKind;
+use mao::interface::table::TableInterface;
+use mao::journal::GameEvent;
+use mao::player::Player;
+use mao::render::play_screen::RuleActionResult;
+use mao::rule::inference::RuleModel;
+use mao::rule::{Action, Event, Rule};
+
+fn played(player: usize, value: Value, suit: Suit) -> GameEvent {
+    GameEvent::CardPlayed {
+        player,
+        card: Card::new(value, suit),
+        legal: true,
+    }
+}
+
+#[test]
+fn automatic_actions_are_inferred_from_rule_invocations() {
+    let mut model = RuleModel::new();
+    for event in [
+        played(0, Value::Ace, Suit::Hearts),
+        GameEvent::RuleTriggered {
+            player: 0,
+            result: RuleActionResult::Skip { who: 1 },
+        },
+        played(0, Value::Four, Suit::Hearts),
+        played(1, Value::Ace, Suit::Clubs),
+        GameEvent::RuleTriggered {
+            player: 1,
+            result: RuleActionResult::Skip { who: 0 },
+        },
+        played(1, Value::Ace, Suit::Clubs),
+        GameEvent::RuleTriggered {
+            player: 1,
+            result: RuleActionResult::Skip { who: 0 },
+        },
+        GameEvent::RoundWon {
+            round: 1,
+            player: 1,
+        },
+    ] {
+        model.observe(&event);
+    }
+
+    let beliefs = model.beliefs();
+    assert!(beliefs.contains(Rule::new(Event::ValuePlayed(Value::Ace), Action::Skip(1))));
+    assert_eq!(beliefs.rules().count(), 1);
+}
+
+#[test]
+fn phrases_are_inferred_from_penalties() {
+    let mut model = RuleModel::new();
+    for event in [
+        played(0, Value::Four, Suit::Spades),
+        GameEvent::Penalty {
+            player: 0,
+            incs: vec![],
+            fails: vec!["four of spades".to_string()],
+            card_error: false,
+            drawn: vec![],
+        },
+        played(1, Value::Nine, Suit::Spades),
+        GameEvent::Penalty {
+            player: 1,
+            incs: vec![],
+            fails: vec!["nine of spades".to_string()],
+            card_error: false,
+            drawn: vec![],
+        },
+        played(0, Value::Four, Suit::Hearts),
+        GameEvent::Penalty {
+            player: 0,
+            incs: vec!["four of hearts".to_string()],
+            fails: vec![],
+            card_error: false,
+            drawn: vec![],
+        },
+    ] {
+        model.observe(&event);
+    }
+
+    // It's learned something for spades, which works for ones it hasn't seen.
+    assert_eq!(
+        model.phrases_for(Card::new(Value::King, Suit::Spades)),
+        vec!["king of spades".to_string()]
+    );
+    assert!(model
+        .phrases_for(Card::new(Value::Four, Suit::Hearts))
+        .is_empty());
+}
+
+#[test]
+fn beliefs_match_the_real_automatic_rules() {
+    let players: Vec<_> = (1..=4)
+        .map(|i| Player::bot(format!("Bot {i}"), BotKind::Expert))
+        .collect();
+    let mut game = Game::with_seed(players, 12);
+    let mut table = TableInterface::bots_only(game.players(), game.seed());
+    for _ in 0..3 {
+        game.round(&mut table).unwrap();
+    }
+
+    let mut model = RuleModel::new();
+    model.observe_journal(game.journal());
+
+    // Everything it believes happens automatically is a real rule.
+    for rule in model.beliefs().rules() {
+        if !matches!(rule.action(), Action::Say(_)) {
+            assert!(game.rule_map().contains(rule.clone()), "{rule:?}");
+        }
+    }
+}
@@ -0,0 +1,87 @@
+use mao::card::{Card, Suit, Value};
+use mao::deck::{Deck, DeckKind};
+use mao::game::config::GameConfig;
+use mao::game::Game;
+use mao::interface::bot::BotKind;
+use mao::interface::table::TableInterface;
+use mao::player::Player;
+use mao::rule::legality::PlayPolicy;
+use mao::rule::{Event, Rule};
+
+#[test]
+fn kinds_of_deck() {
+    assert_eq!(Deck::of_kind(DeckKind::Standard, 0), Deck::default_52());
+    assert_eq!(DeckKind::Piquet.size(), 32);
+    assert_eq!(Deck::of_kind(DeckKind::Euchre, 0).size(), 24);
+    assert!(!DeckKind::Euchre.contains(Value::Eight));
+    assert!(DeckKind::Piquet.contains(Value::Ace));
+
+    let deck = Deck::of_kind(DeckKind::Standard, 2);
+    let jokers: Vec<Card> = deck
+        .cards()
+        .iter()
+        .copied()
+        .filter(Card::is_joker)
+        .collect();
+    assert_eq!(deck.size(), 54);
+    assert_eq!(jokers, vec![Card::joker(true), Card::joker(false)]);
+
+    assert_eq!("piquet".parse(), Ok(DeckKind::Piquet));
+    assert_eq!("24".parse(), Ok(DeckKind::Euchre));
+    assert!("pinochle".parse::<DeckKind>().is_err());
+}
+
+#[test]
+fn jokers_have_a_colour_but_no_suit() {
+    let red = Card::joker(true);
+    assert_eq!(red.to_string(), "Red Joker");
+    assert_eq!(
+        Game::parse_message("{card}, {suit}".to_string(), red),
+        "red joker, red"
+    );
+
+    assert!(Event::RedPlayed.arg_matches(red));
+    assert!(Event::ValuePlayed(Value::Joker).arg_matches(red));
+    assert!(!Event::SuitPlayed(Suit::Hearts).arg_matches(red));
+    assert!(!Event::BlackPlayed.arg_matches(red));
+
+    // Jokers are wild.
+    let seven = Card::new(Value::Seven, Suit::Clubs);
+    assert!(PlayPolicy::SameSuitOrValue.allows(seven, red));
+    assert!(PlayPolicy::SameSuitOrValue.allows(red, seven));
+}
+
+#[test]
+fn rules_about_jokers() {
+    let rule = Rule::parse("on joker skip").unwrap();
+    assert_eq!(rule.event(), Event::ValuePlayed(Value::Joker));
+    assert_eq!(rule.to_string(), "on value joker skip");
+    assert_eq!(Rule::parse(&rule.to_string()), Ok(rule));
+
+    // Only the red joker is hearts, and the black joker spades.
+    assert_eq!(
+        Event::parse("card joker of hearts"),
+        Ok(Event::CardPlayed(Card::joker(true)))
+    );
+    assert!(Event::parse("card joker of clubs").is_err());
+    assert!(!Event::CardPlayed(Card::new(Value::Joker, Suit::Diamonds)).is_possible());
+    assert_eq!(Card::every().filter(Card::is_joker).count(), 2);
+}
+
+#[test]
+fn games_with_other_decks() {
+    let bots = (1..=3)
+        .map(|i| Player::bot(format!("Bot {i}"), BotKind::Hard))
+        .collect();
+    let config = GameConfig::default()
+        .with_deck(DeckKind::Euchre)
+        .with_jokers(1)
+        .with_decks(2);
+    let mut game = Game::with_config(bots, 5, config);
+    let mut table = TableInterface::bots_only(game.players(), game.seed());
+
+    game.start_round();
+    assert_eq!(game.total_cards(), 50);
+    game.round(&mut table).unwrap();
+    assert!(game.check_cards("the round").is_ok());
+}
@@ -0,0 +1,137 @@
+use mao::card::{Card, Suit, Value};
+use mao::game::Game;
+use mao::interface::scripted::ScriptedInterface;
+use mao::journal::GameEvent;
+use mao::players;
+use mao::rule::legality::PlayPolicy;
+use mao::rule::rule_map::RuleMap;
+use mao::rule::{Action, Event, Rule, Situation, Trigger};
+
+/// Whether the first card of `played` can be played on the rest, with `rules` and `policy`.
+fn allowed(rules: &[(&str, Action)], policy: PlayPolicy, played: &[Card]) -> bool {
+    let mut rule_map = RuleMap::default();
+    rule_map.set_play_policy(policy);
+    for (event, action) in rules {
+        rule_map.push(Rule::new(Event::parse(event).unwrap(), action.clone()));
+    }
+
+    rule_map.allows(&Situation {
+        trigger: Trigger::CardPlayed,
+        played,
+        cards_left: 5,
+    })
+}
+
+#[test]
+fn legality_rules_parse() {
+    assert_eq!(Action::parse("allow"), Ok(Action::Allow));
+    assert_eq!(Action::parse("Forbid"), Ok(Action::Forbid));
+    assert_eq!(Event::parse("same suit"), Ok(Event::SameSuitAsPrevious));
+    assert!(Action::parse("forbid 2").is_err());
+}
+
+#[test]
+fn play_policies() {
+    let top = Card::new(Value::Four, Suit::Hearts);
+    let diamond = Card::new(Value::Nine, Suit::Diamonds);
+    let four = Card::new(Value::Four, Suit::Spades);
+    let club = Card::new(Value::Nine, Suit::Clubs);
+
+    assert!(PlayPolicy::SameColorOrValue.allows(top, diamond));
+    assert!(PlayPolicy::SameColorOrValue.allows(top, four));
+    assert!(!PlayPolicy::SameColorOrValue.allows(top, club));
+
+    assert!(!PlayPolicy::SameSuitOrValue.allows(top, diamond));
+    assert!(PlayPolicy::SameSuitOrValue.allows(top, four));
+    assert!(PlayPolicy::SameSuitOrValue.allows(Card::new(Value::Two, Suit::Hearts), top));
+
+    assert!(PlayPolicy::AnyCard.allows(top, club));
+    assert_eq!(PlayPolicy::default(), PlayPolicy::SameColorOrValue);
+}
+
+#[test]
+fn allow_and_forbid_rules() {
+    let policy = PlayPolicy::SameSuitOrValue;
+    let queen = Card::new(Value::Queen, Suit::Clubs);
+    let jack = |suit| Card::new(Value::Jack, suit);
+    let hearts = Card::new(Value::Two, Suit::Hearts);
+
+    // Queens are wild.
+    let wild = [("queen", Action::Allow)];
+    assert!(!allowed(&[], policy, &[queen, hearts]));
+    assert!(allowed(&wild, policy, &[queen, hearts]));
+
+    // No jack on a jack, even with jacks being wild.
+    let no_jacks = [
+        ("jack", Action::Allow),
+        ("jack twice in a row", Action::Forbid),
+    ];
+    assert!(allowed(&no_jacks, policy, &[jack(Suit::Spades), hearts]));
+    assert!(!allowed(
+        &no_jacks,
+        policy,
+        &[jack(Suit::Spades), jack(Suit::Clubs)]
+    ));
+
+    // Following suit, and not just matching the value.
+    let follow = [("not same suit", Action::Forbid)];
+    let four = Card::new(Value::Four, Suit::Hearts);
+    assert!(allowed(&follow, policy, &[hearts, four]));
+    assert!(!allowed(
+        &follow,
+        policy,
+        &[Card::new(Value::Four, Suit::Clubs), four]
+    ));
+
+    // The first card of the pile can't be illegal.
+    assert!(allowed(&follow, policy, &[hearts]));
+}
+
+#[test]
+fn forbidden_cards_are_penalized() {
+    // A game where Alice has a card that doesn't follow suit.
+    let (mut game, play) = (0..)
+        .find_map(|seed| {
+            let mut game = Game::with_seed(players!["Alice", "Bob"], seed);
+            game.start_round();
+            let top = game.used_pile()[0];
+            let play = game.players()[0]
+                .hand()
+                .iter()
+                .position(|card| card.suit() != top.suit())?;
+            Some((game, play))
+        })
+        .unwrap();
+    game.set_play_policy(PlayPolicy::AnyCard);
+    game.add_rule(Rule::new(
+        Event::parse("not same suit").unwrap(),
+        Action::Forbid,
+    ))
+    .unwrap();
+
+    let top = game.used_pile()[0];
+    let expected: Vec<_> = (0..game.players()[0].num_cards())
+        .filter(|&i| game.players()[0].hand()[i].suit() == top.suit())
+        .collect();
+    assert_eq!(game.legal_plays(), expected);
+    // Nobody is told about the rule, so everything looks legal to Alice.
+    assert_eq!(game.view(0).legal_plays().len(), 7);
+
+    let script = format!("{}\n\nthank you", play + 1);
+    assert!(game.play(&mut ScriptedInterface::new(&script)).is_err());
+    assert!(game.journal().events().iter().any(|event| matches!(
+        event,
+        GameEvent::CardPlayed {
+            player: 0,
+            legal: false,
+            ..
+        }
+    )));
+    assert!(game.journal().events().iter().any(|event| matches!(
+        event,
+        GameEvent::Penalty {
+            card_error: true,
+            ..
+        }
+    )));
+}
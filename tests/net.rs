@@ -0,0 +1,151 @@
+use mao::game::Game;
+use mao::interface::scripted::ScriptedInterface;
+use mao::interface::{Notice, PlayerInterface};
+use mao::journal::GameEvent;
+use mao::net::{Client, ClientMessage, RemoteTable};
+use mao::player::Player;
+use mao::render::engine::RenderError;
+use mao::replay::Input;
+use mao::rule::priority::{ActionOption, Priority};
+use mao::rule::{Action, Event, Rule, RuleChange};
+use std::io::{BufRead, BufReader, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::thread;
+
+/// Joins the game at `addr` as a player that doesn't use `Client`, so it can send answers that couldn't be made with an interface. Each of `answers` is sent for the next `request`, and nothing else is answered.
+fn mallory<const N: usize>(addr: SocketAddr, request: &str, answers: [Input; N]) {
+    let stream = TcpStream::connect(addr).unwrap();
+    let reader = BufReader::new(stream.try_clone().unwrap());
+    let mut writer = stream;
+    let mut send = |message: ClientMessage| {
+        writeln!(writer, "{}", serde_json::to_string(&message).unwrap()).unwrap();
+    };
+
+    send(ClientMessage::Join {
+        name: "Mallory".to_string(),
+    });
+    let mut lines = reader.lines().map(Result::unwrap);
+    for answer in answers {
+        lines.find(|line| line.contains(request)).unwrap();
+        send(ClientMessage::Answer(answer));
+    }
+}
+
+#[test]
+fn players_join_and_play_over_localhost() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    // Joining one at a time, so who sits where is known.
+    let players = thread::spawn(move || {
+        let mut alice = Client::connect(addr, "Alice").unwrap();
+        let mut bob = Client::connect(addr, "Bob").unwrap();
+        assert_eq!((alice.seat(), bob.seat()), (0, 1));
+
+        // Alice leaves after her first turn.
+        let alice = thread::spawn(move || alice.play(&mut ScriptedInterface::new("d\nthank you")));
+        let mut interface = ScriptedInterface::new("d\nthank you");
+        let result = bob.play(&mut interface);
+        (alice.join().unwrap(), result, interface)
+    });
+
+    let (mut table, names) = RemoteTable::accept(&listener, 2).unwrap();
+    let mut game = Game::with_seed(names.into_iter().map(Player::new).collect(), 5);
+    let result = game.play(&mut table);
+    table.finish();
+
+    let (alice_result, bob_result, bob_interface) = players.join().unwrap();
+    assert!(matches!(result, Err(RenderError::Network(_))));
+    assert!(matches!(alice_result, Err(RenderError::ScriptEnded)));
+    assert!(bob_result.is_ok());
+
+    let names: Vec<_> = game.players().iter().map(|p| p.name().as_str()).collect();
+    assert_eq!(names, ["Alice", "Bob"]);
+    let drawn: Vec<_> = game
+        .journal()
+        .events()
+        .iter()
+        .filter_map(|event| match event {
+            GameEvent::CardDrawn { player, .. } => Some(*player),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(drawn, [0, 1]);
+
+    // Bob was told about every turn, not just his own.
+    let turns = bob_interface
+        .notices()
+        .iter()
+        .filter(|notice| matches!(notice, Notice::NewTurn))
+        .count();
+    assert_eq!(turns, 3);
+}
+
+#[test]
+fn rules_from_players_are_checked() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    let answers = [
+        Input::RuleChange(RuleChange::Add(Rule::new(
+            Event::CardDrawn,
+            Action::Skip(2),
+        ))),
+        Input::RuleChange(RuleChange::Add(Rule::new(
+            Event::CardDrawn,
+            Action::Skip(1_000_000),
+        ))),
+        Input::RuleChange(RuleChange::Modify {
+            old: Rule::new(Event::CardDrawn, Action::Skip(2)),
+            new: Rule::new(Event::CardDrawn, Action::Say("{nope}".to_string())),
+        }),
+        Input::RuleChange(RuleChange::Add(Rule::new(
+            Event::CardDrawn,
+            Action::Reverse,
+        ))),
+    ];
+    let player = thread::spawn(move || mallory(addr, "RequestRule", answers));
+
+    let (mut table, names) = RemoteTable::accept(&listener, 1).unwrap();
+    let game = Game::with_seed(names.into_iter().map(Player::new).collect(), 5);
+
+    assert_eq!(
+        table.request_rule(&game.view(0), 0).unwrap(),
+        RuleChange::Add(Rule::new(Event::CardDrawn, Action::Skip(2)))
+    );
+    // The invalid rules are asked for again, until there's one that could have been typed in.
+    assert_eq!(
+        table.request_rule(&game.view(0), 0).unwrap(),
+        RuleChange::Add(Rule::new(Event::CardDrawn, Action::Reverse))
+    );
+    player.join().unwrap();
+}
+
+#[test]
+fn priorities_from_players_are_checked() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    let partial: Priority = serde_json::from_str(r#"{"queue":["Skip"]}"#).unwrap();
+    let reordered = Priority::default().reordered(&[ActionOption::Skip]);
+    let answers = [
+        Input::Priority(Some(Priority::default())),
+        Input::Priority(Some(partial)),
+        Input::Priority(Some(reordered.clone())),
+    ];
+    let player = thread::spawn(move || mallory(addr, "RequestPriority", answers));
+
+    let (mut table, names) = RemoteTable::accept(&listener, 1).unwrap();
+    let game = Game::with_seed(names.into_iter().map(Player::new).collect(), 5);
+
+    assert_eq!(
+        table.request_priority(&game.view(0), 0).unwrap(),
+        Some(Priority::default())
+    );
+    // The partial priority is asked for again.
+    assert_eq!(
+        table.request_priority(&game.view(0), 0).unwrap(),
+        Some(reordered)
+    );
+    player.join().unwrap();
+}
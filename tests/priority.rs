@@ -0,0 +1,132 @@
+use mao::game::Game;
+use mao::interface::replay::ReplayInterface;
+use mao::interface::scripted::ScriptedInterface;
+use mao::journal::GameEvent;
+use mao::players;
+use mao::render::engine::RenderError;
+use mao::render::play_screen::RuleActionResult;
+use mao::replay::Input;
+use mao::rule::priority::{ActionOption, IncompletePriority, Priority};
+use mao::rule::{Action, Event, Rule};
+use rand::SeedableRng;
+use rand_chacha::ChaCha12Rng;
+
+/// What the rules did after Alice drew a card, with rules to reverse and skip whenever a card is drawn.
+fn reverse_and_skip(priority: Priority) -> Vec<RuleActionResult> {
+    let mut game = Game::with_priority(players!["Alice", "Bob", "Carol"], 0, priority);
+    for action in [Action::Reverse, Action::Skip(1)] {
+        game.add_rule(Rule::new(Event::CardDrawn, action)).unwrap();
+    }
+
+    assert!(game
+        .play(&mut ScriptedInterface::new("d\nthank you"))
+        .is_err());
+    game.journal()
+        .events()
+        .iter()
+        .filter_map(|event| match event {
+            GameEvent::RuleTriggered { result, .. } => Some(result.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn reordering() {
+    let priority = Priority::default();
+    let reordered = priority.parse_reorder("Skip, swap hands").unwrap();
+
+    assert_eq!(
+        reordered.iter().take(3).copied().collect::<Vec<_>>(),
+        vec![
+            ActionOption::Skip,
+            ActionOption::SwapHands,
+            ActionOption::Say
+        ]
+    );
+    assert_eq!(reordered.len(), priority.len());
+    assert_eq!(priority.parse_reorder(""), Ok(priority.clone()));
+    assert!(priority.parse_reorder("skip, jump").is_err());
+    assert!(reordered
+        .to_string()
+        .starts_with("skip, swap hands, say, draw"));
+}
+
+#[test]
+fn shuffled_priorities_have_every_action() {
+    let mut rng = ChaCha12Rng::seed_from_u64(1);
+    let shuffled = Priority::shuffled(&mut rng);
+
+    let mut options: Vec<_> = shuffled.iter().map(ActionOption::name).collect();
+    let mut expected: Vec<_> = Priority::default().iter().map(ActionOption::name).collect();
+    options.sort();
+    expected.sort();
+    assert_eq!(options, expected);
+}
+
+#[test]
+fn actions_happen_in_priority_order() {
+    // Reversing first means Carol is skipped, skipping first means Bob is.
+    assert_eq!(
+        reverse_and_skip(Priority::default()),
+        vec![RuleActionResult::Reverse, RuleActionResult::Skip { who: 2 }]
+    );
+    assert_eq!(
+        reverse_and_skip(Priority::default().reordered(&[ActionOption::Skip])),
+        vec![RuleActionResult::Skip { who: 1 }, RuleActionResult::Reverse]
+    );
+}
+
+#[test]
+fn winners_can_reorder_the_priority() {
+    let mut game = Game::new(players!["Alice", "Bob"]);
+
+    game.reorder_priority(0, &mut ScriptedInterface::new("\n"))
+        .unwrap();
+    assert_eq!(game.priority(), &Priority::default());
+
+    game.reorder_priority(1, &mut ScriptedInterface::new("reverse, skip"))
+        .unwrap();
+    assert_eq!(
+        game.priority().iter().take(2).copied().collect::<Vec<_>>(),
+        vec![ActionOption::Reverse, ActionOption::Skip]
+    );
+    assert_eq!(game.view(0).priority(), game.priority());
+    assert_eq!(
+        game.journal().events(),
+        &[GameEvent::PriorityChanged {
+            player: 1,
+            priority: game.priority().clone(),
+        }]
+    );
+
+    assert!(matches!(
+        game.reorder_priority(0, &mut ScriptedInterface::new("first")),
+        Err(RenderError::ScriptInvalid(1, _))
+    ));
+}
+
+#[test]
+fn priorities_need_every_action_once() {
+    let mut options: Vec<_> = Priority::default().iter().copied().collect();
+    assert_eq!(Priority::new(options.clone()), Ok(Priority::default()));
+    assert!(Priority::default().is_complete());
+
+    options.push(ActionOption::Skip);
+    assert_eq!(Priority::new(options.clone()), Err(IncompletePriority));
+    options.truncate(3);
+    assert_eq!(Priority::new(options), Err(IncompletePriority));
+    assert_eq!(Priority::new(vec![]), Err(IncompletePriority));
+
+    // One that didn't come from `Priority`, eg. from another player, can't be used.
+    let partial: Priority = serde_json::from_str(r#"{"queue":["Skip"]}"#).unwrap();
+    assert!(!partial.is_complete());
+    let mut game = Game::new(players!["Alice", "Bob"]);
+    assert_eq!(game.set_priority(partial.clone()), Err(IncompletePriority));
+    assert_eq!(game.priority(), &Priority::default());
+
+    let mut interface = ReplayInterface::new(vec![Input::Priority(Some(partial))]);
+    game.reorder_priority(0, &mut interface).unwrap();
+    assert_eq!(game.priority(), &Priority::default());
+    assert!(game.journal().events().is_empty());
+}
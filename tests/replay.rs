@@ -0,0 +1,119 @@
+use mao::game::Game;
+use mao::interface::recording::RecordingInterface;
+use mao::interface::replay::ReplayInterface;
+use mao::interface::scripted::ScriptedInterface;
+use mao::interface::Move;
+use mao::players;
+use mao::render::engine::RenderError;
+use mao::replay::{Input, Replay};
+use mao::rule::{Rule, RuleChange};
+
+const SCRIPT: &str = "d\n\nthank you\nd\nthank you\nd\nthank you";
+
+/// Plays `SCRIPT` on a new game with `seed`, and records it.
+fn record(seed: u64) -> (Game, Replay) {
+    let mut game = Game::with_seed(players!["Alice", "Bob"], seed);
+    let mut scripted = ScriptedInterface::new(SCRIPT);
+    let mut recording = RecordingInterface::new(&mut scripted);
+    assert!(game.play(&mut recording).is_err());
+
+    let replay = Replay::new(
+        seed,
+        vec!["Alice".to_string(), "Bob".to_string()],
+        recording.into_inputs(),
+    );
+    (game, replay)
+}
+
+#[test]
+fn recording_keeps_every_input() {
+    let (_, replay) = record(3);
+
+    assert_eq!(
+        replay.inputs,
+        vec![
+            Input::Move(Move::Draw),
+            Input::Speech(vec![]),
+            Input::Speech(vec!["thank you".to_string()]),
+            Input::Move(Move::Draw),
+            Input::Speech(vec!["thank you".to_string()]),
+            Input::Move(Move::Draw),
+            Input::Speech(vec!["thank you".to_string()]),
+        ]
+    );
+}
+
+#[test]
+fn replay_plays_the_same_game() {
+    let (original, replay) = record(3);
+
+    let path = std::env::temp_dir().join(format!("mao-replay-{}.json", std::process::id()));
+    replay.save(&path).unwrap();
+    let loaded = Replay::load(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(loaded, replay);
+
+    let mut game = loaded.new_game();
+    let mut interface = ReplayInterface::new(loaded.inputs);
+    assert!(matches!(
+        game.play(&mut interface),
+        Err(RenderError::ScriptEnded)
+    ));
+
+    assert_eq!(game.players(), original.players());
+    assert_eq!(game.draw_pile(), original.draw_pile());
+    assert_eq!(game.journal().events(), original.journal().events());
+}
+
+#[test]
+fn replay_that_does_not_fit_the_game_is_an_error() {
+    let mut game = Game::with_seed(players!["Alice", "Bob"], 3);
+    let mut interface = ReplayInterface::new(vec![Input::NextRound(true)]);
+
+    assert!(matches!(
+        game.play(&mut interface),
+        Err(RenderError::ReplayMismatch(1))
+    ));
+}
+
+#[test]
+fn replay_with_a_shuffled_priority_plays_the_same_game() {
+    let mut original = Game::with_seed(players!["Alice", "Bob"], 3);
+    original.shuffle_priority();
+    let mut scripted = ScriptedInterface::new(SCRIPT);
+    let mut recording = RecordingInterface::new(&mut scripted);
+    assert!(original.play(&mut recording).is_err());
+
+    // Shuffling the priority doesn't change how the cards are dealt.
+    let (unshuffled, _) = record(3);
+    assert_eq!(original.players(), unshuffled.players());
+
+    let replay = Replay::new(
+        3,
+        vec!["Alice".to_string(), "Bob".to_string()],
+        recording.into_inputs(),
+    )
+    .with_priority(original.priority().clone());
+    let mut game = replay.new_game();
+    let mut interface = ReplayInterface::new(replay.inputs);
+    assert!(game.play(&mut interface).is_err());
+
+    assert_eq!(game.players(), original.players());
+    assert_eq!(game.draw_pile(), original.draw_pile());
+    assert_eq!(game.journal().events(), original.journal().events());
+}
+
+#[test]
+fn rule_changes_are_shown_as_rules() {
+    let old = Rule::parse("on hearts say \"hello\"").unwrap();
+    let new = Rule::parse("on spades skip").unwrap();
+
+    assert_eq!(
+        Input::RuleChange(RuleChange::Add(old.clone())).to_string(),
+        "Made the rule 'on suit hearts say \"hello\"'"
+    );
+    assert_eq!(
+        Input::RuleChange(RuleChange::Modify { old, new }).to_string(),
+        "Changed the rule 'on suit hearts say \"hello\"' to 'on suit spades skip'"
+    );
+}
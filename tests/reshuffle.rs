@@ -0,0 +1,74 @@
+mod common;
+
+use common::bots;
+use mao::card::{Card, Suit, Value};
+use mao::deck::Deck;
+use mao::game::config::GameConfig;
+use mao::game::Game;
+use mao::interface::bot::BotKind;
+use mao::interface::table::TableInterface;
+use mao::journal::GameEvent;
+use mao::player::Player;
+use mao::players;
+
+/// How many times the used pile was shuffled back, and how many decks were added, in `game`.
+fn refills(game: &Game) -> (usize, usize) {
+    let events = game.journal().events();
+    let count = |f: fn(&GameEvent) -> bool| events.iter().filter(|event| f(event)).count();
+    (
+        count(|event| matches!(event, GameEvent::DiscardsReshuffled { .. })),
+        count(|event| matches!(event, GameEvent::DeckAdded { .. })),
+    )
+}
+
+#[test]
+fn decks_only_deal_what_they_have() {
+    let mut deck = Deck::from(vec![
+        Card::new(Value::Two, Suit::Hearts),
+        Card::new(Value::Three, Suit::Hearts),
+        Card::new(Value::Four, Suit::Hearts),
+    ]);
+    let mut player = Player::new("Alice".to_string());
+
+    let bottom = deck.split_off(2);
+    assert_eq!(bottom.cards(), &[Card::new(Value::Four, Suit::Hearts)]);
+
+    assert_eq!(deck.deal(5, &mut player).len(), 2);
+    assert_eq!(deck.size(), 0);
+    assert_eq!(player.num_cards(), 2);
+    assert!(deck.deal(1, &mut player).is_empty());
+}
+
+#[test]
+fn the_used_pile_is_shuffled_back_in() {
+    // Big hands leave hardly anything to draw, so the used pile has to be used again.
+    let config = GameConfig::default()
+        .with_hand_size(20)
+        .with_refill_below(0)
+        .with_add_decks(false);
+    let mut game = Game::with_config(bots(2, BotKind::Hard), 3, config);
+    let mut table = TableInterface::bots_only(game.players(), game.seed());
+
+    game.start_round();
+    assert_eq!(game.total_cards(), 52);
+    game.round(&mut table).unwrap();
+
+    let (reshuffles, decks) = refills(&game);
+    assert!(reshuffles > 0);
+    assert_eq!(decks, 0);
+    assert_eq!(game.total_cards(), 52);
+}
+
+#[test]
+fn decks_are_added_as_a_last_resort() {
+    // Everybody is dealt the whole deck, so there's no card to start the used pile with.
+    let config = GameConfig::default()
+        .with_hand_size(26)
+        .with_refill_below(0)
+        .with_add_decks(false);
+    let mut game = Game::with_config(players!["Alice", "Bob"], 3, config);
+
+    game.start_round();
+    assert_eq!(refills(&game), (0, 1));
+    assert_eq!(game.total_cards(), 104);
+}
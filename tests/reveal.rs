@@ -0,0 +1,73 @@
+use mao::game::Game;
+use mao::interface::bot::BotKind;
+use mao::interface::scripted::ScriptedInterface;
+use mao::interface::table::TableInterface;
+use mao::interface::Notice;
+use mao::player::Player;
+use mao::players;
+use mao::rule::{Action, Author, Event, Rule};
+
+/// The rule in `game` with the same event and action as `rule`.
+fn find(game: &Game, rule: &Rule) -> Rule {
+    game.rule_map()
+        .rules()
+        .find(|r| *r == rule)
+        .cloned()
+        .unwrap()
+}
+
+#[test]
+fn rules_remember_who_made_them() {
+    let mut game = Game::new(players!["Alice", "Bob"]);
+    game.create_rule(1, &mut ScriptedInterface::new("hearts\nsay hello"))
+        .unwrap();
+
+    let hello = Rule::new(
+        Event::parse("hearts").unwrap(),
+        Action::parse("say hello").unwrap(),
+    );
+    assert_eq!(
+        find(&game, &hello).author(),
+        Some(Author {
+            player: 1,
+            round: 0
+        })
+    );
+
+    // The rules the game starts with weren't made by anybody.
+    let thank_you = Rule::new(Event::CardDrawn, Action::Say(String::from("thank you")));
+    assert_eq!(find(&game, &thank_you).author(), None);
+}
+
+#[test]
+fn rules_count_the_penalties_they_cause() {
+    let mut game = Game::with_seed(players!["Alice", "Bob"], 3);
+    // Alice draws without saying "thank you", and thanks the penalty.
+    assert!(game
+        .play(&mut ScriptedInterface::new("d\n\nthank you"))
+        .is_err());
+
+    let drawn = Rule::new(Event::CardDrawn, Action::Say(String::from("thank you")));
+    let penalized = Rule::new(Event::Penalized, Action::Say(String::from("thank you")));
+    assert_eq!(find(&game, &drawn).penalties(), 1);
+    assert_eq!(find(&game, &penalized).penalties(), 0);
+}
+
+#[test]
+fn rules_are_revealed_when_the_game_ends() {
+    let players = (1..=2)
+        .map(|i| Player::bot(format!("Bot {i}"), BotKind::Hard))
+        .collect();
+    let mut game = Game::with_seed(players, 21);
+    let mut people = ScriptedInterface::new("n");
+    let mut table = TableInterface::new(game.players(), game.seed(), &mut people);
+
+    game.play(&mut table).unwrap();
+
+    match people.notices().last() {
+        Some(Notice::RulesRevealed(rules)) => {
+            assert_eq!(rules.len(), game.rule_map().rules().count());
+        }
+        notice => panic!("expected the rules to be revealed, got {:?}", notice),
+    }
+}
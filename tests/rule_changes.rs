@@ -0,0 +1,123 @@
+use mao::game::{AddingRuleError, Game, MAX_RULE_TRIES};
+use mao::interface::scripted::ScriptedInterface;
+use mao::journal::GameEvent;
+use mao::players;
+use mao::rule::{Action, Event, Rule, RuleChange};
+
+fn rule(event: &str, action: &str) -> Rule {
+    Rule::new(Event::parse(event).unwrap(), Action::parse(action).unwrap())
+}
+
+/// A game where Alice has made a rule to say "hello" for every heart.
+fn game_with_rule() -> Game {
+    let mut game = Game::new(players!["Alice", "Bob"]);
+    game.create_rule(0, &mut ScriptedInterface::new("add\nhearts\nsay hello"))
+        .unwrap();
+    game
+}
+
+#[test]
+fn winners_can_remove_rules() {
+    let mut game = game_with_rule();
+    let hello = rule("hearts", "say hello");
+    assert!(game.rule_map().contains(hello.clone()));
+
+    game.create_rule(1, &mut ScriptedInterface::new("remove\nhearts\nsay hello"))
+        .unwrap();
+    assert!(!game.rule_map().contains(hello.clone()));
+    assert_eq!(
+        game.journal().events().last(),
+        Some(&GameEvent::RuleRemoved {
+            player: 1,
+            rule: hello
+        })
+    );
+}
+
+#[test]
+fn winners_can_modify_rules() {
+    let mut game = game_with_rule();
+
+    game.create_rule(
+        0,
+        &mut ScriptedInterface::new("modify\nhearts\nsay hello\nspades\nsay goodbye"),
+    )
+    .unwrap();
+    assert!(!game.rule_map().contains(rule("hearts", "say hello")));
+    assert!(game.rule_map().contains(rule("spades", "say goodbye")));
+    assert_eq!(
+        game.view(0).own_rules(),
+        vec![rule("spades", "say goodbye")]
+    );
+}
+
+#[test]
+fn describing_a_missing_rule_asks_again() {
+    let mut game = game_with_rule();
+
+    // The first description doesn't match, so Bob gets told and has another go.
+    game.create_rule(
+        1,
+        &mut ScriptedInterface::new("remove\nhearts\nsay goodbye\nremove\nhearts\nsay hello"),
+    )
+    .unwrap();
+    assert!(!game.rule_map().contains(rule("hearts", "say hello")));
+
+    assert!(matches!(
+        game.change_rule(RuleChange::Remove(rule("clubs", "draw 2"))),
+        Err(AddingRuleError::NoSuchRule)
+    ));
+}
+
+#[test]
+fn conflicting_modifications_keep_the_old_rule() {
+    let mut game = game_with_rule();
+    game.add_rule(rule("diamonds", "say hi")).unwrap();
+
+    assert!(matches!(
+        game.change_rule(RuleChange::Modify {
+            old: rule("hearts", "say hello"),
+            new: rule("diamonds", "say hi"),
+        }),
+        Err(AddingRuleError::ConflictingAction)
+    ));
+    assert!(game.rule_map().contains(rule("hearts", "say hello")));
+    assert!(game.rule_map().contains(rule("diamonds", "say hi")));
+}
+
+#[test]
+fn clearing_the_rules() {
+    let mut game = game_with_rule();
+
+    game.clear_rules();
+    assert_eq!(game.rule_map().rules().count(), 0);
+}
+
+#[test]
+fn only_the_changer_sees_rule_changes() {
+    let mut game = game_with_rule();
+    game.create_rule(1, &mut ScriptedInterface::new("remove\nhearts\nsay hello"))
+        .unwrap();
+
+    // Alice never finds out her rule was removed.
+    assert_eq!(game.view(0).own_rules(), vec![rule("hearts", "say hello")]);
+    assert!(game.view(1).own_rules().is_empty());
+    assert!(game
+        .public_view()
+        .events()
+        .iter()
+        .all(|event| !matches!(event, GameEvent::RuleRemoved { .. })));
+}
+
+#[test]
+fn winners_only_get_so_many_tries() {
+    let mut game = game_with_rule();
+    let events = game.journal().events().len();
+
+    // Alice keeps adding the rule she already made, so she never gets to the new one.
+    let script = "add\nhearts\nsay hello\n".repeat(MAX_RULE_TRIES) + "add\nspades\nsay goodbye";
+    game.create_rule(0, &mut ScriptedInterface::new(&script))
+        .unwrap();
+    assert!(!game.rule_map().contains(rule("spades", "say goodbye")));
+    assert_eq!(game.journal().events().len(), events);
+}
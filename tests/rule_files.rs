@@ -0,0 +1,131 @@
+use mao::card::{Card, Suit, Value};
+use mao::game::Game;
+use mao::interface::scripted::ScriptedInterface;
+use mao::players;
+use mao::replay::Replay;
+use mao::rule::dsl::{parse_rules, write_rules, DEFAULT_RULES};
+use mao::rule::{Action, Event, Rule};
+
+#[test]
+fn rules_parse() {
+    assert_eq!(
+        Rule::parse("on value 7 say \"have a nice day\""),
+        Ok(Rule::new(
+            Event::ValuePlayed(Value::Seven),
+            Action::Say("have a nice day".to_string())
+        ))
+    );
+    // "draw" is an event and an action.
+    assert_eq!(
+        Rule::parse("On draw draw 2"),
+        Ok(Rule::new(Event::CardDrawn, Action::Draw(2)))
+    );
+    assert_eq!(
+        Rule::parse("on draw say \"draw\""),
+        Ok(Rule::new(Event::CardDrawn, Action::Say("draw".to_string())))
+    );
+
+    // Messages can only fill in the card, value and suit.
+    assert!(Rule::parse("on value 7 say \"{ nope\"").is_err());
+    assert!(Action::parse("say {oops}").is_err());
+    assert_eq!(
+        Game::parse_message("{oops}".to_string(), Card::new(Value::Two, Suit::Clubs)),
+        "{oops}"
+    );
+
+    assert!(Rule::parse("value 7 skip").is_err());
+    assert!(Rule::parse("on value 7").is_err());
+    assert!(Rule::parse("on value 14 skip").is_err());
+}
+
+#[test]
+fn rules_print_the_way_they_parse() {
+    let rules = [
+        "on card 4 of spades draw 2",
+        "on suit hearts and not face card skip",
+        "on (red or black) and value jack next draws",
+        "on not (value 7 or value 8) pass left",
+        "on (not red) twice in a row swap hands",
+        "on value 7 and not value 7 twice in a row say \"have a nice day\"",
+        "on 0 cards left say \"mao\"",
+        "on same suit or run of 3 or colour changed forbid",
+    ];
+
+    for text in rules {
+        let rule = Rule::parse(text).unwrap();
+        assert_eq!(rule.to_string(), text);
+        assert_eq!(Rule::parse(&rule.to_string()), Ok(rule));
+    }
+}
+
+#[test]
+fn rules_files() {
+    let file = "# House rules\n\non value queen skip 2\n  # wild\non value jack allow\n";
+    assert_eq!(
+        parse_rules(file),
+        Ok(vec![
+            Rule::new(Event::ValuePlayed(Value::Queen), Action::Skip(2)),
+            Rule::new(Event::ValuePlayed(Value::Jack), Action::Allow),
+        ])
+    );
+
+    let issue = parse_rules("on red skip\non blue skip").unwrap_err();
+    assert!(issue.starts_with("line 2:"), "{}", issue);
+
+    let defaults = parse_rules(DEFAULT_RULES).unwrap();
+    assert_eq!(parse_rules(&write_rules(&defaults)), Ok(defaults));
+}
+
+#[test]
+fn games_start_with_the_default_rules_file() {
+    let game = Game::new(players!["Alice", "Bob"]);
+    let defaults = parse_rules(DEFAULT_RULES).unwrap();
+
+    assert_eq!(game.rule_map().rules().count(), defaults.len());
+    for rule in defaults {
+        assert!(game.rule_map().contains(rule));
+    }
+}
+
+#[test]
+fn games_can_use_other_rules() {
+    let mut game = Game::new(players!["Alice", "Bob"]);
+    // In the order the rule map keeps them, by action.
+    let rules = parse_rules("on red draw\non value queen skip").unwrap();
+
+    game.use_rules(rules.clone()).unwrap();
+    assert_eq!(game.rule_map().rules().cloned().collect::<Vec<_>>(), rules);
+
+    // Rules that conflict leave the game's rules alone.
+    let conflicting = parse_rules("on value 5 skip\non value 5 and red skip").unwrap();
+    assert!(game.use_rules(conflicting).is_err());
+    assert_eq!(game.rule_map().rules().cloned().collect::<Vec<_>>(), rules);
+
+    // Replays start with the same rules.
+    let replay = Replay::new(game.seed(), vec!["Alice".into(), "Bob".into()], vec![])
+        .with_rules(rules.clone());
+    assert_eq!(
+        replay
+            .new_game()
+            .rule_map()
+            .rules()
+            .cloned()
+            .collect::<Vec<_>>(),
+        rules
+    );
+}
+
+#[test]
+fn winners_can_write_a_whole_rule_at_once() {
+    let mut game = Game::new(players!["Alice", "Bob"]);
+
+    game.create_rule(0, &mut ScriptedInterface::new("on value 3 say \"three\""))
+        .unwrap();
+    assert!(game
+        .rule_map()
+        .contains(Rule::parse("on value 3 say \"three\"").unwrap()));
+
+    assert!(game
+        .create_rule(0, &mut ScriptedInterface::new("on value 3"))
+        .is_err());
+}
@@ -0,0 +1,61 @@
+use mao::game::Game;
+use mao::interface::scripted::ScriptedInterface;
+use mao::players;
+use std::path::PathBuf;
+
+/// A file in the temp directory for a test to save to.
+fn save_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("mao-{}-{}.json", name, std::process::id()))
+}
+
+#[test]
+fn save_then_load_is_the_same_game() {
+    let mut game = Game::with_seed(players!["Alice", "Bob"], 99);
+    assert!(game
+        .play(&mut ScriptedInterface::new("d\nthank you\nd\nthank you"))
+        .is_err());
+
+    let path = save_path("round-trip");
+    game.save(&path).unwrap();
+    let loaded = Game::load(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(loaded.seed(), game.seed());
+    assert_eq!(loaded.round_n(), game.round_n());
+    assert_eq!(loaded.players(), game.players());
+    assert_eq!(loaded.draw_pile(), game.draw_pile());
+    assert_eq!(loaded.used_pile(), game.used_pile());
+    assert_eq!(loaded.rule_map(), game.rule_map());
+    assert_eq!(loaded.journal().events(), game.journal().events());
+}
+
+#[test]
+fn resumed_game_continues_where_it_was_saved() {
+    let mut uninterrupted = Game::with_seed(players!["Alice", "Bob", "Carol"], 5);
+    assert!(uninterrupted
+        .play(&mut ScriptedInterface::new(
+            "d\nthank you\nd\nthank you\nd\nthank you"
+        ))
+        .is_err());
+
+    // The same game, but saved and loaded again after the first turn.
+    let mut first = Game::with_seed(players!["Alice", "Bob", "Carol"], 5);
+    assert!(first
+        .play(&mut ScriptedInterface::new("d\nthank you"))
+        .is_err());
+
+    let path = save_path("resume");
+    first.save(&path).unwrap();
+    let mut resumed = Game::load(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert!(resumed.round_in_progress());
+    assert!(resumed
+        .play(&mut ScriptedInterface::new("d\nthank you\nd\nthank you"))
+        .is_err());
+
+    assert_eq!(resumed.players(), uninterrupted.players());
+    assert_eq!(resumed.draw_pile(), uninterrupted.draw_pile());
+    assert_eq!(resumed.used_pile(), uninterrupted.used_pile());
+    assert_eq!(resumed.journal().events(), uninterrupted.journal().events());
+}
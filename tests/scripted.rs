@@ -0,0 +1,115 @@
+use mao::card::Value;
+use mao::game::Game;
+use mao::interface::scripted::ScriptedInterface;
+use mao::interface::Notice;
+use mao::journal::GameEvent;
+use mao::players;
+use mao::render::engine::RenderError;
+use mao::rule::{Action, Event, Rule};
+
+fn script(name: &str) -> ScriptedInterface {
+    ScriptedInterface::load(format!(
+        "{}/tests/scripts/{name}",
+        env!("CARGO_MANIFEST_DIR")
+    ))
+    .expect("script should exist")
+}
+
+#[test]
+fn play_stops_when_the_script_ends() {
+    let mut game = Game::new(players!["Alice", "Bob"]);
+    let mut interface = script("draw_only.txt");
+
+    let result = game.play(&mut interface);
+
+    assert!(matches!(result, Err(RenderError::ScriptEnded)));
+    assert_eq!(interface.remaining(), 0);
+    for player in game.players() {
+        assert_eq!(player.num_cards(), 10);
+    }
+    assert_eq!(game.total_cards(), 52);
+}
+
+#[test]
+fn forgetting_thank_you_is_penalized() {
+    let mut game = Game::new(players!["Alice", "Bob"]);
+    let mut interface = script("forgot_thank_you.txt");
+
+    assert!(matches!(
+        game.play(&mut interface),
+        Err(RenderError::ScriptEnded)
+    ));
+
+    // 7 dealt, 1 drawn, 1 penalty
+    assert_eq!(game.players()[0].num_cards(), 9);
+    assert_eq!(game.players()[1].num_cards(), 7);
+}
+
+#[test]
+fn winner_creates_rule() {
+    let mut game = Game::new(players!["Alice", "Bob"]);
+    let mut interface = ScriptedInterface::new("value is 3\nsay three");
+
+    game.create_rule(0, &mut interface).unwrap();
+
+    assert!(game.rule_map().contains(Rule::new(
+        Event::ValuePlayed(Value::Three),
+        Action::Say(String::from("three"))
+    )));
+}
+
+#[test]
+fn conflicting_rule_is_asked_for_again() {
+    let mut game = Game::new(players!["Alice", "Bob"]);
+    let mut interface = ScriptedInterface::new("value is 7\nsay have a nice day\nvalue is 9\nskip");
+
+    game.create_rule(1, &mut interface).unwrap();
+
+    assert!(matches!(
+        interface.notices()[..],
+        [Notice::RuleExists { winner: 1 }]
+    ));
+    assert!(game
+        .rule_map()
+        .contains(Rule::new(Event::ValuePlayed(Value::Nine), Action::Skip(1))));
+}
+
+#[test]
+fn invalid_input_reports_its_line() {
+    let mut game = Game::new(players!["Alice", "Bob"]);
+    let mut interface = ScriptedInterface::new("# a comment\nnonsense");
+
+    assert!(matches!(
+        game.play(&mut interface),
+        Err(RenderError::ScriptInvalid(2, _))
+    ));
+}
+
+#[test]
+fn journal_records_draws_and_penalties() {
+    let mut game = Game::new(players!["Alice", "Bob"]);
+    let mut interface = script("forgot_thank_you.txt");
+    assert!(game.play(&mut interface).is_err());
+
+    let events = game.journal().events();
+    assert!(matches!(events[0], GameEvent::RoundStarted { round: 1 }));
+    assert!(matches!(events[1], GameEvent::CardDrawn { player: 0, .. }));
+    match &events[2] {
+        GameEvent::Penalty {
+            player,
+            incs,
+            fails,
+            card_error,
+            drawn,
+        } => {
+            assert_eq!(*player, 0);
+            assert!(incs.is_empty());
+            assert_eq!(fails, &vec![String::from("thank you")]);
+            assert!(!card_error);
+            assert_eq!(drawn.len(), 1);
+        }
+        event => panic!("expected a penalty, got {:?}", event),
+    }
+    assert_eq!(events.len(), 3);
+    assert_eq!(game.journal().events_of(1).count(), 0);
+}
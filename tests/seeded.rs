@@ -0,0 +1,31 @@
+use mao::game::Game;
+use mao::interface::scripted::ScriptedInterface;
+use mao::players;
+
+const SCRIPT: &str = "d\nthank you\nd\n\nthank you\nd\nthank you";
+
+/// Plays `SCRIPT` (until it runs out) on a new game with `seed`.
+fn play_seeded(seed: u64) -> Game {
+    let mut game = Game::with_seed(players!["Alice", "Bob", "Carol"], seed);
+    assert!(game.play(&mut ScriptedInterface::new(SCRIPT)).is_err());
+    game
+}
+
+#[test]
+fn same_seed_same_game() {
+    let a = play_seeded(1234);
+    let b = play_seeded(1234);
+
+    assert_eq!(a.seed(), 1234);
+    assert_eq!(a.players(), b.players());
+    assert_eq!(a.draw_pile(), b.draw_pile());
+    assert_eq!(a.used_pile(), b.used_pile());
+}
+
+#[test]
+fn different_seed_different_game() {
+    let a = play_seeded(1);
+    let b = play_seeded(2);
+
+    assert_ne!(a.draw_pile(), b.draw_pile());
+}
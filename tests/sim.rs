@@ -0,0 +1,73 @@
+use mao::card::Suit;
+use mao::game::Game;
+use mao::players;
+use mao::rule::{Action, Event, Rule};
+use mao::sim::{simulate, SimConfig};
+
+fn small() -> SimConfig {
+    SimConfig {
+        games: 20,
+        rounds: 2,
+        seed: 3,
+        ..SimConfig::default()
+    }
+}
+
+#[test]
+fn stats_add_up() {
+    let stats = simulate(&small());
+
+    assert_eq!(stats.games, 20);
+    assert_eq!(stats.rounds + stats.unfinished_rounds, 40);
+    assert_eq!(stats.wins_by_seat.iter().sum::<usize>(), stats.rounds);
+    // 4 players are dealt 28 cards, which doesn't leave enough for the draw pile.
+    assert!(stats.decks_added >= stats.rounds);
+    assert!(stats.avg_round_length() > 4.0);
+}
+
+#[test]
+fn same_config_same_stats() {
+    let a = simulate(&small());
+    let b = simulate(&small());
+
+    assert_eq!(format!("{a}"), format!("{b}"));
+}
+
+#[test]
+fn rounds_nobody_can_win_are_given_up_on() {
+    // Every card played is replaced by drawing one.
+    let mut rule_map = Game::new(players!["A", "B"]).rule_map().clone();
+    for suit in [Suit::Clubs, Suit::Diamonds, Suit::Hearts, Suit::Spades] {
+        rule_map.push(Rule::new(Event::SuitPlayed(suit), Action::Draw(1)));
+    }
+
+    let stats = simulate(&SimConfig {
+        games: 3,
+        rule_map: Some(rule_map),
+        max_turns: 200,
+        ..SimConfig::default()
+    });
+
+    assert_eq!(stats.rounds, 0);
+    assert_eq!(stats.unfinished_rounds, 3);
+}
+
+#[test]
+fn penalties_are_put_down_to_the_rule_that_asked() {
+    // A rule on what was played before, which the card on its own can't tell.
+    let rule = Rule::parse("on colour changed say \"new colour\"").unwrap();
+    let mut rule_map = Game::new(players!["A", "B"]).rule_map().clone();
+    rule_map.push(rule.clone());
+
+    let stats = simulate(&SimConfig {
+        rule_map: Some(rule_map),
+        ..small()
+    });
+
+    assert!(stats.penalties[&rule.to_string()] > 0);
+    assert!(!stats
+        .penalties
+        .keys()
+        .any(|reason| reason.contains("failure to say")));
+    assert!(format!("{stats}").contains(&format!("  {rule}\n")));
+}
@@ -0,0 +1,112 @@
+use mao::card::{Card, Suit, Value};
+use mao::game::Game;
+use mao::interface::scripted::ScriptedInterface;
+use mao::journal::GameEvent;
+use mao::players;
+use mao::render::play_screen::RuleActionResult;
+use mao::rule::rule_map::RuleMap;
+use mao::rule::{Action, Event, Rule, Situation, Trigger};
+
+/// Plays `script` on a new game for Alice and Bob with `rules` added to the default rules, and returns the game's journal.
+fn play(rules: &[(&str, Action)], script: &str) -> Vec<GameEvent> {
+    let mut game = Game::new(players!["Alice", "Bob"]);
+    for (event, action) in rules {
+        game.add_rule(Rule::new(Event::parse(event).unwrap(), action.clone()))
+            .unwrap();
+    }
+
+    assert!(game.play(&mut ScriptedInterface::new(script)).is_err());
+    game.journal().events().to_vec()
+}
+
+/// Returns what each player was penalized for failing to say.
+fn fails(events: &[GameEvent]) -> Vec<(usize, Vec<String>)> {
+    events
+        .iter()
+        .filter_map(|event| match event {
+            GameEvent::Penalty { player, fails, .. } => Some((*player, fails.clone())),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn trigger_events_parse() {
+    assert_eq!(Event::parse("draw"), Ok(Event::CardDrawn));
+    assert_eq!(Event::parse("penalized"), Ok(Event::Penalized));
+    assert_eq!(Event::parse("order reversed"), Ok(Event::OrderReversed));
+    assert_eq!(Event::parse("round start"), Ok(Event::RoundStarted));
+    assert_eq!(Event::parse("1 card left"), Ok(Event::CardsLeft(1)));
+    assert_eq!(Event::parse("no cards left"), Ok(Event::CardsLeft(0)));
+
+    // Nothing can be drawn and played at once.
+    assert!(Event::parse("draw and 7").is_err());
+    assert!(Event::parse("not draw").is_err());
+}
+
+#[test]
+fn card_events_only_happen_for_plays() {
+    let played = [Card::new(Value::Three, Suit::Clubs)];
+    let drawn = Situation {
+        trigger: Trigger::CardDrawn,
+        played: &played,
+        cards_left: 8,
+    };
+
+    assert!(!Event::parse("not 7").unwrap().matches(&drawn));
+    assert!(Event::parse("draw or 7").unwrap().matches(&drawn));
+    assert!(Event::parse("not 7")
+        .unwrap()
+        .arg_matches(Card::new(Value::Three, Suit::Clubs)));
+}
+
+#[test]
+fn draw_rules_are_applied() {
+    let events = play(&[("draw", Action::Skip(1))], "d\nthank you\nd\nthank you");
+
+    // Bob is skipped both times, so Alice draws twice.
+    let draws: Vec<_> = events
+        .iter()
+        .filter_map(|event| match event {
+            GameEvent::CardDrawn { player, .. } => Some(*player),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(draws, vec![0, 0]);
+    assert!(events.iter().any(|event| matches!(
+        event,
+        GameEvent::RuleTriggered {
+            result: RuleActionResult::Skip { who: 1 },
+            ..
+        }
+    )));
+}
+
+#[test]
+fn round_start_and_penalty_rules_need_saying() {
+    let rules = [
+        ("round start", Action::Say("good luck".to_string())),
+        ("penalty", Action::Say("sorry".to_string())),
+    ];
+    let events = play(
+        &rules,
+        "d\nthank you\nthank you\nthank you. sorry\nd\nthank you",
+    );
+
+    assert_eq!(
+        fails(&events),
+        vec![
+            (0, vec!["good luck".to_string()]),
+            (0, vec!["sorry".to_string()]),
+        ]
+    );
+}
+
+#[test]
+fn thank_you_is_only_a_default_rule() {
+    let mut game = Game::new(players!["Alice", "Bob"]);
+    game.set_rule_map(RuleMap::default());
+
+    assert!(game.play(&mut ScriptedInterface::new("d\n\nd\n")).is_err());
+    assert!(fails(game.journal().events()).is_empty());
+}
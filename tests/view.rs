@@ -0,0 +1,88 @@
+use mao::card::Value;
+use mao::game::Game;
+use mao::interface::scripted::ScriptedInterface;
+use mao::journal::GameEvent;
+use mao::players;
+use mao::rule::{Action, Event, Rule};
+
+/// A game where Alice has drawn a card and been penalized for not saying "thank you", and then added a rule.
+fn played_game() -> Game {
+    let mut game = Game::with_seed(players!["Alice", "Bob"], 3);
+    assert!(game
+        .play(&mut ScriptedInterface::new("d\n\nthank you"))
+        .is_err());
+    game.create_rule(0, &mut ScriptedInterface::new("value is 3\nsay three"))
+        .unwrap();
+    game
+}
+
+#[test]
+fn players_only_see_their_own_hand() {
+    let game = played_game();
+
+    let alice = game.view(0);
+    let bob = game.view(1);
+    let everybody = game.public_view();
+
+    assert_eq!(alice.hand(), game.players()[0].hand());
+    assert_eq!(bob.hand(), game.players()[1].hand());
+    assert!(everybody.hand().is_empty());
+    for view in [&alice, &bob, &everybody] {
+        assert_eq!(view.players()[0].num_cards(), 9);
+        assert_eq!(view.players()[1].num_cards(), 7);
+        assert_eq!(view.top_card(), game.used_pile().top());
+    }
+}
+
+#[test]
+fn other_players_draws_are_face_down() {
+    let game = played_game();
+
+    let drawn = |events: &[GameEvent]| match (&events[1], &events[2]) {
+        (GameEvent::CardDrawn { card, .. }, GameEvent::Penalty { drawn, .. }) => {
+            (card.is_some(), drawn.iter().all(Option::is_some))
+        }
+        events => panic!("expected a draw and a penalty, got {:?}", events),
+    };
+
+    assert_eq!(drawn(game.view(0).events()), (true, true));
+    assert_eq!(drawn(game.view(1).events()), (false, false));
+    assert_eq!(drawn(game.public_view().events()), (false, false));
+}
+
+#[test]
+fn new_rules_are_secret() {
+    let game = played_game();
+    assert!(game.rule_map().contains(Rule::new(
+        Event::ValuePlayed(Value::Three),
+        Action::Say(String::from("three"))
+    )));
+
+    let made_rule = |events: &[GameEvent]| {
+        events
+            .iter()
+            .any(|event| matches!(event, GameEvent::RuleCreated { .. }))
+    };
+
+    // Only the player who made the rule gets to see it.
+    assert!(made_rule(game.view(0).events()));
+    assert_eq!(game.view(0).own_rules().len(), 1);
+    for view in [game.view(1), game.public_view()] {
+        assert!(!made_rule(view.events()));
+        assert!(view.own_rules().is_empty());
+    }
+}
+
+#[test]
+fn views_only_have_the_round() {
+    let mut game = played_game();
+    game.start_round();
+
+    let view = game.view(0);
+    assert_eq!(view.events(), game.journal().round_events());
+    assert!(matches!(view.events(), [GameEvent::RoundStarted { .. }]));
+    assert_eq!(view.events_start(), game.journal().events().len() - 1);
+
+    // The rules made in earlier rounds are still remembered.
+    assert_eq!(view.own_rules().len(), 1);
+}